@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        errors::{DomainError, DomainResult},
+        repositories::accounts::AccountRepository,
+        value_objects::{
+            account_credentials,
+            account_model::{AccountModel, ChangePasswordModel},
+            roles::Roles,
+        },
+    },
+    infrastructure::argon2_hashing,
+};
+
+#[derive(Debug, Clone)]
+pub struct AccountsUseCase<T>
+where
+    T: AccountRepository + Send + Sync,
+{
+    account_repository: Arc<T>,
+}
+
+impl<T> AccountsUseCase<T>
+where
+    T: AccountRepository + Send + Sync,
+{
+    pub fn new(account_repository: Arc<T>) -> Self {
+        Self { account_repository }
+    }
+
+    pub async fn profile(&self, account_id: i32) -> DomainResult<AccountModel> {
+        let account = self.account_repository.find_by_id(account_id).await?;
+
+        let roles = self.account_repository.roles(account_id).await?;
+
+        Ok(account.to_model(roles))
+    }
+
+    pub async fn change_password(
+        &self,
+        account_id: i32,
+        change_password_model: ChangePasswordModel,
+    ) -> DomainResult<()> {
+        let account = self.account_repository.find_by_id(account_id).await?;
+
+        if !argon2_hashing::verify(change_password_model.old_password, account.password)? {
+            return Err(DomainError::Unauthorized("Invalid password".to_string()));
+        }
+
+        account_credentials::validate_new_password(
+            &change_password_model.new_password,
+            &account.username,
+        )?;
+
+        let hashed_password = argon2_hashing::hash(change_password_model.new_password)?;
+
+        self.account_repository
+            .change_password(account_id, hashed_password)
+            .await?;
+
+        Ok(())
+    }
+
+    // เพิ่ม role ให้ตัวเองได้แค่ Adventurer กับ GuildCommander, Admin ต้องให้ admin คนอื่นเป็นคนให้
+    pub async fn grant_role(&self, account_id: i32, role: Roles) -> DomainResult<AccountModel> {
+        if role == Roles::Admin {
+            return Err(DomainError::Forbidden(
+                "Admin role cannot be self-granted".to_string(),
+            ));
+        }
+
+        self.account_repository.grant_role(account_id, role).await?;
+
+        self.profile(account_id).await
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::moderation_actions::AddModerationActionEntity,
+    errors::{DomainError, DomainResult},
+    repositories::admin::AdminRepository,
+    value_objects::{
+        account_model::AccountModel,
+        admin_model::{AccountsFilter, OverrideQuestStatusModel},
+        moderation_actions::ModerationActions,
+        pagination::Page,
+        quest_event_model::{AddQuestEventModel, QuestEventActor},
+        quest_event_types::QuestEventTypes,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct AdminUseCase<T>
+where
+    T: AdminRepository + Send + Sync,
+{
+    admin_repository: Arc<T>,
+}
+
+impl<T> AdminUseCase<T>
+where
+    T: AdminRepository + Send + Sync,
+{
+    pub fn new(admin_repository: Arc<T>) -> Self {
+        Self { admin_repository }
+    }
+
+    pub async fn accounts(&self, filter: &AccountsFilter) -> DomainResult<Page<AccountModel>> {
+        let accounts = self.admin_repository.accounts(filter).await?;
+
+        let total = self.admin_repository.accounts_counting(filter).await?;
+
+        let items = accounts
+            .into_iter()
+            .map(|(account, roles)| account.to_model(roles))
+            .collect::<Vec<AccountModel>>();
+
+        Ok(Page::new(items, total, filter.page_request()))
+    }
+
+    pub async fn suspend_account(
+        &self,
+        admin_account_id: i32,
+        account_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<()> {
+        // กันไม่ให้ admin ล็อกตัวเองออกจากระบบ
+        if admin_account_id == account_id {
+            return Err(DomainError::Forbidden(
+                "Admins cannot suspend their own account".to_string(),
+            ));
+        }
+
+        self.admin_repository
+            .suspend_account(moderation_action(
+                admin_account_id,
+                ModerationActions::SuspendAccount,
+                account_id,
+                reason,
+            ))
+            .await
+    }
+
+    pub async fn unsuspend_account(
+        &self,
+        admin_account_id: i32,
+        account_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<()> {
+        self.admin_repository
+            .unsuspend_account(moderation_action(
+                admin_account_id,
+                ModerationActions::UnsuspendAccount,
+                account_id,
+                reason,
+            ))
+            .await
+    }
+
+    pub async fn restore_quest(
+        &self,
+        admin_account_id: i32,
+        quest_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<()> {
+        let moderation_action = moderation_action(
+            admin_account_id,
+            ModerationActions::RestoreQuest,
+            quest_id,
+            reason,
+        );
+        let quest_event = AddQuestEventModel::new(
+            QuestEventTypes::Restored,
+            QuestEventActor::admin(admin_account_id),
+        )
+        .with_detail("reason", &moderation_action.reason);
+
+        self.admin_repository
+            .restore_quest(moderation_action, quest_event)
+            .await
+    }
+
+    pub async fn override_quest_status(
+        &self,
+        admin_account_id: i32,
+        quest_id: i32,
+        override_quest_status_model: OverrideQuestStatusModel,
+    ) -> DomainResult<()> {
+        override_quest_status_model.validate()?;
+
+        let status = override_quest_status_model.status;
+        let moderation_action = moderation_action(
+            admin_account_id,
+            ModerationActions::OverrideQuestStatus,
+            quest_id,
+            Some(override_quest_status_model.reason),
+        );
+        let quest_event = AddQuestEventModel::new(
+            QuestEventTypes::StatusOverridden,
+            QuestEventActor::admin(admin_account_id),
+        )
+        .with_detail("to_status", status)
+        .with_detail("reason", &moderation_action.reason);
+
+        self.admin_repository
+            .override_quest_status(status, moderation_action, quest_event)
+            .await
+    }
+}
+
+fn moderation_action(
+    admin_account_id: i32,
+    action: ModerationActions,
+    target_id: i32,
+    reason: Option<String>,
+) -> AddModerationActionEntity {
+    AddModerationActionEntity {
+        admin_account_id,
+        action,
+        target_id,
+        details: None,
+        reason: reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty()),
+        created_at: chrono::Utc::now().naive_utc(),
+    }
+}
@@ -1,10 +1,17 @@
-use anyhow::Result;
 use std::sync::Arc;
 
 use crate::{
     domain::{
+        errors::DomainResult,
         repositories::adventurers::AdventurerRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
+        value_objects::{
+            adventurer_model::{
+                AdventurerModel, JoinedQuestModel, JoinedQuestsFilter, QuestCommanderModel,
+                RegisterAdventurerModel, UpdateAdventurerProfileModel,
+            },
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+        },
     },
     infrastructure::argon2_hashing,
 };
@@ -30,7 +37,9 @@ where
     pub async fn register(
         &self,
         mut register_adventurer_model: RegisterAdventurerModel,
-    ) -> Result<i32> {
+    ) -> DomainResult<i32> {
+        register_adventurer_model.validate()?;
+
         let hashed_password = argon2_hashing::hash(register_adventurer_model.password)?;
 
         register_adventurer_model.password = hashed_password;
@@ -42,4 +51,67 @@ where
 
         Ok(adventurer_id)
     }
+
+    pub async fn profile(&self, adventurer_id: i32) -> DomainResult<AdventurerModel> {
+        let adventurer = self.adventurer_repository.find_by_id(adventurer_id).await?;
+
+        Ok(adventurer.to_model())
+    }
+
+    pub async fn update_profile(
+        &self,
+        adventurer_id: i32,
+        update_adventurer_profile_model: UpdateAdventurerProfileModel,
+    ) -> DomainResult<AdventurerModel> {
+        let adventurer = self
+            .adventurer_repository
+            .update_profile(adventurer_id, update_adventurer_profile_model.to_entity()?)
+            .await?;
+
+        Ok(adventurer.to_model())
+    }
+
+    pub async fn deactivate(&self, adventurer_id: i32) -> DomainResult<()> {
+        self.adventurer_repository
+            .deactivate(
+                adventurer_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Left,
+                    QuestEventActor::adventurer(adventurer_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn joined_quests(
+        &self,
+        adventurer_id: i32,
+        filter: &JoinedQuestsFilter,
+    ) -> DomainResult<Vec<JoinedQuestModel>> {
+        let adventurer = self.adventurer_repository.find_by_id(adventurer_id).await?;
+
+        let results = self
+            .adventurer_repository
+            .joined_quests(&adventurer, filter)
+            .await?;
+
+        let joined_quests = results
+            .iter()
+            .map(
+                |(quest, adventurer_count, guild_commander)| JoinedQuestModel {
+                    quest: quest.to_model(*adventurer_count),
+                    guild_commander: QuestCommanderModel {
+                        id: guild_commander.id,
+                        username: guild_commander.username.clone(),
+                        display_name: guild_commander.display_name.clone(),
+                    },
+                },
+            )
+            .collect::<Vec<JoinedQuestModel>>();
+
+        Ok(joined_quests)
+    }
 }
@@ -1,180 +1,343 @@
-use anyhow::{Ok, Result};
 use chrono::{Duration, Utc};
 use std::sync::Arc;
 
 use crate::{
-    config::config_loader::{get_adventurer_secret, get_guild_commander_secret},
-    domain::repositories::{
-        adventurers::AdventurerRepository, guild_commanders::GuildCommanderRepository,
+    domain::{
+        entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenRotation},
+        errors::{DomainError, DomainResult},
+        repositories::{
+            accounts::AccountRepository, login_throttles::LoginThrottleRepository,
+            refresh_tokens::RefreshTokenRepository,
+        },
+        value_objects::{
+            login_throttle_policy::LoginThrottlePolicy, roles::Roles, session_model::SessionModel,
+        },
     },
     infrastructure::{
         argon2_hashing,
         jwt_authentication::{
             self,
             authentication_model::LoginModel,
-            jwt_model::{Claims, Passport, Roles},
+            jwt_model::{Claims, Passport, TokenTypes},
         },
     },
 };
 
-pub struct AuthenticationUseCase<T1, T2>
+pub struct AuthenticationUseCase<T1, T2, T3>
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
-    adventurer_repository: Arc<T1>,
-    guild_commander_repository: Arc<T2>,
+    account_repository: Arc<T1>,
+    refresh_token_repository: Arc<T2>,
+    login_throttle_repository: Arc<T3>,
 }
 
-impl<T1, T2> AuthenticationUseCase<T1, T2>
+impl<T1, T2, T3> AuthenticationUseCase<T1, T2, T3>
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
-    pub fn new(adventurer_repository: Arc<T1>, guild_commander_repository: Arc<T2>) -> Self {
+    pub fn new(
+        account_repository: Arc<T1>,
+        refresh_token_repository: Arc<T2>,
+        login_throttle_repository: Arc<T3>,
+    ) -> Self {
         Self {
-            adventurer_repository,
-            guild_commander_repository,
+            account_repository,
+            refresh_token_repository,
+            login_throttle_repository,
         }
     }
 
-    pub async fn adventurer_login(&self, login_model: LoginModel) -> Result<Passport> {
-        let secret_env = get_adventurer_secret()?;
+    // required_role ใช้กับ endpoint login เดิมของแต่ละ role ที่ยังเปิดไว้ให้ client เก่า
+    // client_ip เป็น None เมื่อไม่ได้เปิดนับ login ผิดต่อ IP
+    pub async fn login(
+        &self,
+        login_model: LoginModel,
+        client_ip: Option<String>,
+        required_role: Option<Roles>,
+    ) -> DomainResult<Passport> {
+        let throttle_keys = LoginThrottleKeys::new(&login_model.username, client_ip.as_deref());
+
+        self.ensure_not_throttled(&throttle_keys).await?;
 
-        let adventurer = self
-            .adventurer_repository
+        let account = match self
+            .account_repository
             .find_by_username(login_model.username.clone())
-            .await?;
+            .await
+        {
+            Ok(account) => Some(account),
+            Err(DomainError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
 
-        if !argon2_hashing::verify(login_model.password, adventurer.password)? {
-            return Err(anyhow::anyhow!("Invalid password"));
-        }
+        let credentials = account
+            .as_ref()
+            .map(|account| (account.id, account.password.clone()));
 
-        let access_token_claims = Claims {
-            sub: adventurer.id.to_string(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let account_id = self
+            .verify_credentials(&throttle_keys, login_model.password, credentials)
+            .await?;
 
-        let refresh_token_claims = Claims {
-            sub: adventurer.id.to_string(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        // บอกว่าถูกระงับหลังรหัสผ่านถูกแล้วเท่านั้น ไม่ให้ใช้เดาได้ว่า username ไหนโดนแบน
+        if account.is_some_and(|account| account.suspended_at.is_some()) {
+            return Err(DomainError::Forbidden("Account is suspended".to_string()));
+        }
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+        let roles = self.account_repository.roles(account_id).await?;
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+        if let Some(required_role) = required_role {
+            if !roles.contains(&required_role) {
+                return Err(DomainError::Forbidden(format!(
+                    "Account does not have the {} role",
+                    required_role
+                )));
+            }
+        }
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+        self.start_session(account_id, roles).await
     }
 
-    pub async fn adventurer_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_adventurer_secret()?;
-
-        let claims =
-            jwt_authentication::verify_token(secret_env.refresh_secret.clone(), refresh_token)?;
+    pub async fn refresh_token(&self, refresh_token: String) -> DomainResult<Passport> {
+        self.rotate_session(refresh_token).await
+    }
 
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::Adventurer,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+    pub async fn logout(&self, refresh_token: String) -> DomainResult<()> {
+        // token ที่ไม่รู้จักถือว่า logout แล้ว
+        match self
+            .refresh_token_repository
+            .revoke_family(jwt_authentication::hash_token(&refresh_token))
+            .await
+        {
+            Ok(_) | Err(DomainError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
 
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::Adventurer,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+    pub async fn logout_session(&self, account_id: i32, session_id: String) -> DomainResult<()> {
+        match self
+            .refresh_token_repository
+            .revoke_session(session_id, account_id)
+            .await
+        {
+            Ok(_) | Err(DomainError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+    pub async fn sessions(
+        &self,
+        account_id: i32,
+        current_session_id: String,
+    ) -> DomainResult<Vec<SessionModel>> {
+        let sessions = self
+            .refresh_token_repository
+            .active_sessions(account_id)
+            .await?;
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+        Ok(sessions
+            .iter()
+            .map(|session| session.to_session_model(&current_session_id))
+            .collect::<Vec<SessionModel>>())
+    }
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+    pub async fn revoke_session(&self, account_id: i32, session_id: String) -> DomainResult<()> {
+        self.refresh_token_repository
+            .revoke_session(session_id, account_id)
+            .await
     }
 
-    pub async fn guild_commander_login(&self, login_model: LoginModel) -> Result<Passport> {
-        let secret_env = get_guild_commander_secret()?;
+    pub async fn revoke_all_sessions(&self, account_id: i32) -> DomainResult<usize> {
+        self.refresh_token_repository
+            .revoke_all_sessions(account_id)
+            .await
+    }
 
-        let guild_commander = self
-            .guild_commander_repository
-            .find_by_username(login_model.username.clone())
+    async fn ensure_not_throttled(&self, throttle_keys: &LoginThrottleKeys) -> DomainResult<()> {
+        let locked_until = self
+            .login_throttle_repository
+            .locked_until(throttle_keys.all())
             .await?;
 
-        if !argon2_hashing::verify(login_model.password, guild_commander.password)? {
-            return Err(anyhow::anyhow!("Invalid password"));
+        if let Some(locked_until) = locked_until {
+            let retry_after = (locked_until - Utc::now().naive_utc()).num_seconds().max(1);
+
+            return Err(DomainError::TooManyRequests(format!(
+                "Too many failed login attempts, try again in {} seconds",
+                retry_after
+            )));
         }
 
-        let access_token_claims = Claims {
-            sub: guild_commander.id.to_string(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        Ok(())
+    }
 
-        let refresh_token_claims = Claims {
-            sub: guild_commander.id.to_string(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
+    // ไม่ว่าไม่พบ user หรือรหัสผิดก็ตอบ error เดียวกันและใช้เวลาเท่ากัน
+    async fn verify_credentials(
+        &self,
+        throttle_keys: &LoginThrottleKeys,
+        password: String,
+        credentials: Option<(i32, String)>,
+    ) -> DomainResult<i32> {
+        let account_id = match credentials {
+            Some((account_id, password_hash)) => {
+                argon2_hashing::verify(password, password_hash)?.then_some(account_id)
+            }
+            None => {
+                argon2_hashing::dummy_verify(password)?;
+                None
+            }
         };
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+        let Some(account_id) = account_id else {
+            self.login_throttle_repository
+                .record_failure(
+                    throttle_keys.username.clone(),
+                    LoginThrottlePolicy::per_username(),
+                )
+                .await?;
+            if let Some(ip) = &throttle_keys.ip {
+                self.login_throttle_repository
+                    .record_failure(ip.clone(), LoginThrottlePolicy::per_ip())
+                    .await?;
+            }
+
+            return Err(DomainError::Unauthorized(
+                "Invalid username or password".to_string(),
+            ));
+        };
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+        // login ผ่านล้างเฉพาะของ username ไม่งั้นใช้ account ตัวเองล้างตัวนับของ IP ได้
+        self.login_throttle_repository
+            .reset(throttle_keys.username.clone())
+            .await?;
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+        Ok(account_id)
     }
 
-    pub async fn guild_commander_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_guild_commander_secret()?;
-
-        let claims =
-            jwt_authentication::verify_token(secret_env.refresh_secret.clone(), refresh_token)?;
+    async fn start_session(&self, account_id: i32, roles: Vec<Roles>) -> DomainResult<Passport> {
+        let refresh_expires_at = Utc::now() + Duration::days(7);
+        let session_id = jwt_authentication::generate_token_id();
+
+        let passport = generate_passport(
+            account_id.to_string(),
+            session_id.clone(),
+            roles,
+            refresh_expires_at.timestamp() as usize,
+        )?;
+
+        self.refresh_token_repository
+            .issue(AddRefreshTokenEntity {
+                family_id: session_id,
+                token_hash: jwt_authentication::hash_token(&passport.refresh_token),
+                account_id,
+                expires_at: refresh_expires_at.naive_utc(),
+                created_at: Utc::now().naive_utc(),
+            })
+            .await?;
 
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::GuildCommander,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        Ok(passport)
+    }
 
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::GuildCommander,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+    async fn rotate_session(&self, refresh_token: String) -> DomainResult<Passport> {
+        let claims = jwt_authentication::verify_token(refresh_token.clone(), TokenTypes::Refresh)
+            .map_err(|e| DomainError::Unauthorized(e.to_string()))?;
+
+        let account_id = claims
+            .sub
+            .parse::<i32>()
+            .map_err(|_| DomainError::Unauthorized("Invalid refresh token".to_string()))?;
+
+        // role อาจถูกเพิ่มหรือถอดระหว่าง session เลยอ่านจาก DB ใหม่ทุกครั้งที่ refresh
+        let roles = self.account_repository.roles(account_id).await?;
+
+        // refresh token ใหม่หมดอายุพร้อม family เดิม
+        let passport = generate_passport(claims.sub, claims.sid, roles, claims.exp)?;
+
+        let rotation = self
+            .refresh_token_repository
+            .rotate(
+                jwt_authentication::hash_token(&refresh_token),
+                jwt_authentication::hash_token(&passport.refresh_token),
+            )
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => {
+                    DomainError::Unauthorized("Refresh token not recognized".to_string())
+                }
+                e => e,
+            })?;
+
+        match rotation {
+            RefreshTokenRotation::Rotated => Ok(passport),
+            RefreshTokenRotation::Revoked => Err(DomainError::Unauthorized(
+                "Refresh token has been revoked".to_string(),
+            )),
+            RefreshTokenRotation::ReuseDetected => Err(DomainError::Unauthorized(
+                "Refresh token reuse detected, please login again".to_string(),
+            )),
+        }
+    }
+}
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.secret, &access_token_claims)?;
+struct LoginThrottleKeys {
+    username: String,
+    ip: Option<String>,
+}
 
-        let refresh_token =
-            jwt_authentication::generate_token(secret_env.refresh_secret, &refresh_token_claims)?;
+impl LoginThrottleKeys {
+    fn new(username: &str, client_ip: Option<&str>) -> Self {
+        Self {
+            username: format!("username:{}", username.trim().to_lowercase()),
+            ip: client_ip.map(|client_ip| format!("ip:{}", client_ip)),
+        }
+    }
 
-        Ok(Passport {
-            access_token,
-            refresh_token,
-        })
+    fn all(&self) -> Vec<String> {
+        std::iter::once(self.username.clone())
+            .chain(self.ip.clone())
+            .collect()
     }
 }
+
+fn generate_passport(
+    sub: String,
+    session_id: String,
+    roles: Vec<Roles>,
+    refresh_exp: usize,
+) -> DomainResult<Passport> {
+    let access_token_claims = Claims {
+        sub: sub.clone(),
+        roles: roles.clone(),
+        typ: TokenTypes::Access,
+        iss: jwt_authentication::ISSUER.to_string(),
+        aud: jwt_authentication::AUDIENCE.to_string(),
+        exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+        iat: Utc::now().timestamp() as usize,
+        jti: jwt_authentication::generate_token_id(),
+        sid: session_id.clone(),
+    };
+
+    let refresh_token_claims = Claims {
+        sub,
+        roles,
+        typ: TokenTypes::Refresh,
+        iss: jwt_authentication::ISSUER.to_string(),
+        aud: jwt_authentication::AUDIENCE.to_string(),
+        exp: refresh_exp,
+        iat: Utc::now().timestamp() as usize,
+        jti: jwt_authentication::generate_token_id(),
+        sid: session_id,
+    };
+
+    let access_token = jwt_authentication::generate_token(&access_token_claims)?;
+
+    let refresh_token = jwt_authentication::generate_token(&refresh_token_claims)?;
+
+    Ok(Passport {
+        access_token,
+        refresh_token,
+    })
+}
@@ -1,38 +1,53 @@
-use anyhow::{Ok, Result};
 use std::sync::Arc;
 
 use crate::domain::{
+    errors::{DomainError, DomainResult},
     repositories::{
-        crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+        adventurers::AdventurerRepository, crew_switchboard::CrewSwitchboardRepository,
+        quest_viewing::QuestViewingRepository,
     },
     value_objects::{
-        quest_adventurer_junction::{QuestAdventurerJunction, MAX_ADVENTURERS_PER_QUEST},
-        quest_statuses::QuestStatuses,
+        crew_membership_states::CrewMembershipStates,
+        quest_adventurer_junction::{InviteAdventurerModel, QuestAdventurerJunction},
+        quest_event_model::{AddQuestEventModel, QuestEventActor},
+        quest_event_types::QuestEventTypes,
     },
 };
 
-pub struct CrewSwitchboardUseCase<T1, T2>
+pub struct CrewSwitchboardUseCase<T1, T2, T3>
 where
     T1: CrewSwitchboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
 {
     crew_switchboard_repository: Arc<T1>,
     quest_viewing_repository: Arc<T2>,
+    adventurer_repository: Arc<T3>,
 }
 
-impl<T1, T2> CrewSwitchboardUseCase<T1, T2>
+impl<T1, T2, T3> CrewSwitchboardUseCase<T1, T2, T3>
 where
     T1: CrewSwitchboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
 {
-    pub fn new(crew_switchboard_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_switchboard_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        adventurer_repository: Arc<T3>,
+    ) -> Self {
         Self {
             crew_switchboard_repository,
             quest_viewing_repository,
+            adventurer_repository,
         }
     }
 
-    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+    pub async fn join(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> DomainResult<CrewMembershipStates> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
         let adventurer_count = self
@@ -40,43 +55,162 @@ where
             .adventurer_counting_by_quest_id(quest_id)
             .await?;
 
-        let quest_status_condition = quest.status == QuestStatuses::Open.to_string()
-            || quest.status == QuestStatuses::Failed.to_string();
+        // เช็คก่อนรอบหนึ่ง ส่วน repository จะเช็คซ้ำอีกครั้งภายใต้ row lock
+        quest.ensure_joinable(adventurer_count)?;
 
-        let adventurers_count_condition = adventurer_count < MAX_ADVENTURERS_PER_QUEST;
+        let state = self
+            .crew_switchboard_repository
+            .join(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                AddQuestEventModel::new(
+                    QuestEventTypes::Joined,
+                    QuestEventActor::adventurer(adventurer_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
+            .await?;
 
-        if !adventurers_count_condition {
-            return Err(anyhow::anyhow!("Quest is full"));
-        }
+        Ok(state)
+    }
+    pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> DomainResult<()> {
+        // สถานะ quest เช็คใน repository เพราะคำขอที่ยัง Pending ถอนได้ตลอด
+        self.quest_viewing_repository.view_details(quest_id).await?;
 
-        if !quest_status_condition {
-            return Err(anyhow::anyhow!("Quest is not started"));
-        }
+        self.crew_switchboard_repository
+            .leave(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                AddQuestEventModel::new(
+                    QuestEventTypes::Left,
+                    QuestEventActor::adventurer(adventurer_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+    pub async fn kick(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> DomainResult<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        quest.ensure_owned_by(guild_commander_id)?;
+        quest.ensure_crew_editable()?;
 
         self.crew_switchboard_repository
-            .join(QuestAdventurerJunction {
-                quest_id,
-                adventurer_id,
-            })
+            .kick(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Kicked,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
             .await?;
 
         Ok(())
     }
-    pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+    pub async fn invite(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        invite_adventurer_model: InviteAdventurerModel,
+    ) -> DomainResult<i32> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
-        let leave_condition = quest.status == QuestStatuses::Open.to_string()
-            || quest.status == QuestStatuses::Failed.to_string();
+        quest.ensure_owned_by(guild_commander_id)?;
 
-        if !leave_condition {
-            return Err(anyhow::anyhow!("Quest is not leaveable"));
-        }
+        let adventurer = self
+            .adventurer_repository
+            .find_by_username(invite_adventurer_model.username)
+            .await
+            .map_err(|e| match e {
+                DomainError::NotFound(_) => {
+                    DomainError::NotFound("Adventurer not found".to_string())
+                }
+                e => e,
+            })?;
+
+        self.crew_switchboard_repository
+            .invite(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id: adventurer.id,
+                },
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Invited,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                )
+                .with_detail("adventurer_id", adventurer.id),
+            )
+            .await?;
+
+        Ok(adventurer.id)
+    }
+    pub async fn approve(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> DomainResult<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        quest.ensure_owned_by(guild_commander_id)?;
+
+        self.crew_switchboard_repository
+            .approve(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Approved,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+    pub async fn reject(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> DomainResult<()> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+
+        quest.ensure_owned_by(guild_commander_id)?;
 
         self.crew_switchboard_repository
-            .leave(QuestAdventurerJunction {
-                quest_id,
-                adventurer_id,
-            })
+            .reject(
+                QuestAdventurerJunction {
+                    quest_id,
+                    adventurer_id,
+                },
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Rejected,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                )
+                .with_detail("adventurer_id", adventurer_id),
+            )
             .await?;
 
         Ok(())
@@ -1,10 +1,18 @@
-use anyhow::Result;
 use std::sync::Arc;
 
 use crate::{
     domain::{
+        errors::DomainResult,
         repositories::guild_commanders::GuildCommanderRepository,
-        value_objects::guild_commander_model::RegisterGuildCommanderModel,
+        value_objects::{
+            guild_commander_model::{
+                GuildCommanderModel, OwnedQuestsFilter, OwnedQuestsModel, QuestStatusBreakdown,
+                RegisterGuildCommanderModel, UpdateGuildCommanderProfileModel,
+            },
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_model::QuestModel,
+        },
     },
     infrastructure::argon2_hashing,
 };
@@ -30,7 +38,9 @@ where
     pub async fn register(
         &self,
         mut register_guild_commander_model: RegisterGuildCommanderModel,
-    ) -> Result<i32> {
+    ) -> DomainResult<i32> {
+        register_guild_commander_model.validate()?;
+
         let hashed_password = argon2_hashing::hash(register_guild_commander_model.password)?;
 
         register_guild_commander_model.password = hashed_password;
@@ -42,4 +52,69 @@ where
 
         Ok(guild_commander_id)
     }
+
+    pub async fn profile(&self, guild_commander_id: i32) -> DomainResult<GuildCommanderModel> {
+        let guild_commander = self
+            .guild_commanders_repository
+            .find_by_id(guild_commander_id)
+            .await?;
+
+        Ok(guild_commander.to_model())
+    }
+
+    pub async fn owned_quests(
+        &self,
+        guild_commander_id: i32,
+        filter: &OwnedQuestsFilter,
+    ) -> DomainResult<OwnedQuestsModel> {
+        let results = self
+            .guild_commanders_repository
+            .owned_quests(guild_commander_id, filter)
+            .await?;
+
+        let status_counts = self
+            .guild_commanders_repository
+            .owned_quest_status_counting(guild_commander_id)
+            .await?;
+
+        let quests = results
+            .iter()
+            .map(|(quest, adventurer_count)| quest.to_model(*adventurer_count))
+            .collect::<Vec<QuestModel>>();
+
+        Ok(OwnedQuestsModel {
+            quests,
+            status_breakdown: QuestStatusBreakdown::new(&status_counts),
+        })
+    }
+
+    pub async fn update_profile(
+        &self,
+        guild_commander_id: i32,
+        update_guild_commander_profile_model: UpdateGuildCommanderProfileModel,
+    ) -> DomainResult<GuildCommanderModel> {
+        let guild_commander = self
+            .guild_commanders_repository
+            .update_profile(
+                guild_commander_id,
+                update_guild_commander_profile_model.to_entity()?,
+            )
+            .await?;
+
+        Ok(guild_commander.to_model())
+    }
+
+    pub async fn deactivate(&self, guild_commander_id: i32) -> DomainResult<()> {
+        self.guild_commanders_repository
+            .deactivate(
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Removed,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
 }
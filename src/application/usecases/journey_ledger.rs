@@ -1,12 +1,14 @@
 use crate::domain::{
+    errors::DomainResult,
     repositories::{
         journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
     },
     value_objects::{
-        quest_adventurer_junction::MAX_ADVENTURERS_PER_QUEST, quest_statuses::QuestStatuses,
+        quest_event_model::{AddQuestEventModel, QuestEventActor},
+        quest_event_types::QuestEventTypes,
+        quest_statuses::QuestStatuses,
     },
 };
-use anyhow::Result;
 use std::sync::Arc;
 
 pub struct JourneyLedgerUseCase<T1, T2>
@@ -30,60 +32,81 @@ where
         }
     }
 
-    pub async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    pub async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> DomainResult<i32> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
+        quest.ensure_owned_by(guild_commander_id)?;
+
         let adventurer_count = self
             .quest_viewing_repository
             .adventurer_counting_by_quest_id(quest_id)
             .await?;
 
-        let condition_to_update = (quest.status == QuestStatuses::Open.to_string()
-            || quest.status == QuestStatuses::Failed.to_string())
-            && adventurer_count > 0
-            && adventurer_count <= MAX_ADVENTURERS_PER_QUEST;
-
-        if !condition_to_update {
-            return Err(anyhow::anyhow!("Quest is not in journey"));
-        }
+        quest.status.transition_to(
+            QuestStatuses::InJourney,
+            adventurer_count,
+            quest.crew_limits(),
+        )?;
 
         let result = self
             .journey_ledger_repository
-            .in_journey(quest_id, guild_commander_id)
+            .in_journey(
+                quest_id,
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Departed,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                ),
+            )
             .await?;
 
         Ok(result)
     }
 
-    pub async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    pub async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> DomainResult<i32> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
-        let condition_to_update = quest.status == QuestStatuses::InJourney.to_string();
+        quest.ensure_owned_by(guild_commander_id)?;
 
-        if !condition_to_update {
-            return Err(anyhow::anyhow!("Quest is not in journey"));
-        }
+        // เช็คก่อนรอบหนึ่ง repository จะเช็คซ้ำภายใต้ row lock, จำนวน crew ไม่มีผลกับการจบ quest
+        quest
+            .status
+            .transition_to(QuestStatuses::Completed, 0, quest.crew_limits())?;
 
         let result = self
             .journey_ledger_repository
-            .to_completed(quest_id, guild_commander_id)
+            .to_completed(
+                quest_id,
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Completed,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                ),
+            )
             .await?;
 
         Ok(result)
     }
 
-    pub async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    pub async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> DomainResult<i32> {
         let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
-        let condition_to_update = quest.status == QuestStatuses::InJourney.to_string();
+        quest.ensure_owned_by(guild_commander_id)?;
 
-        if !condition_to_update {
-            return Err(anyhow::anyhow!("Quest is not in journey"));
-        }
+        quest
+            .status
+            .transition_to(QuestStatuses::Failed, 0, quest.crew_limits())?;
 
         let result = self
             .journey_ledger_repository
-            .to_failed(quest_id, guild_commander_id)
+            .to_failed(
+                quest_id,
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Failed,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                ),
+            )
             .await?;
 
         Ok(result)
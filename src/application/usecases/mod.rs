@@ -1,3 +1,5 @@
+pub mod accounts;
+pub mod admin;
 pub mod adventurers;
 pub mod authentication;
 pub mod crew_switchboard;
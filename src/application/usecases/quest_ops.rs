@@ -1,8 +1,12 @@
 use crate::domain::{
+    errors::DomainResult,
     repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-    value_objects::quest_model::{AddQuestModel, EditQuestModel, QuestModel},
+    value_objects::{
+        quest_event_model::{AddQuestEventModel, QuestEventActor},
+        quest_event_types::QuestEventTypes,
+        quest_model::{AddQuestModel, EditQuestModel, TransferQuestOwnershipModel},
+    },
 };
-use anyhow::Result;
 use std::sync::Arc;
 pub struct QuestOpsUseCase<T1, T2>
 where
@@ -29,48 +33,87 @@ where
         &self,
         guild_commander_id: i32,
         add_quest_model: AddQuestModel,
-    ) -> Result<i32> {
-        let add_quest_entity = add_quest_model.to_entity(guild_commander_id);
-        let quest_id = self.quest_ops_repository.add(add_quest_entity).await?;
+    ) -> DomainResult<i32> {
+        let add_quest_entity = add_quest_model.to_entity(guild_commander_id)?;
+        let quest_event = AddQuestEventModel::new(
+            QuestEventTypes::Created,
+            QuestEventActor::guild_commander(guild_commander_id),
+        )
+        .with_detail("name", &add_quest_entity.name)
+        .with_detail("min_crew", add_quest_entity.min_crew)
+        .with_detail("max_crew", add_quest_entity.max_crew)
+        .with_detail("approval_required", add_quest_entity.approval_required);
+
+        let quest_id = self
+            .quest_ops_repository
+            .add(add_quest_entity, quest_event)
+            .await?;
         Ok(quest_id)
     }
 
+    // เจ้าของ จำนวน crew และ crew limit ใหม่เช็คใน repository ภายใต้ row lock
     pub async fn edit(
         &self,
         quest_id: i32,
         guild_commander_id: i32,
         edit_quest_model: EditQuestModel,
-    ) -> Result<i32> {
-        let adventurer_count = self
-            .quest_viewing_repository
-            .adventurer_counting_by_quest_id(quest_id)
-            .await?;
+    ) -> DomainResult<i32> {
+        let quest_event = AddQuestEventModel::new(
+            QuestEventTypes::Edited,
+            QuestEventActor::guild_commander(guild_commander_id),
+        )
+        .with_detail("changes", &edit_quest_model);
 
-        if adventurer_count > 0 {
-            return Err(anyhow::anyhow!("Quest is already started"));
-        }
-
-        let edit_quest_entity = edit_quest_model.to_entity(guild_commander_id);
+        let edit_quest_entity = edit_quest_model.to_entity();
         let quest_id = self
             .quest_ops_repository
-            .edit(quest_id, edit_quest_entity)
+            .edit(quest_id, guild_commander_id, edit_quest_entity, quest_event)
             .await?;
         Ok(quest_id)
     }
 
-    pub async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
-        let adventurer_count = self
-            .quest_viewing_repository
-            .adventurer_counting_by_quest_id(quest_id)
+    // เจ้าของ สถานะ และจำนวน crew เช็คใน repository ภายใต้ row lock
+    pub async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> DomainResult<()> {
+        self.quest_ops_repository
+            .remove(
+                quest_id,
+                guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::Removed,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                ),
+            )
             .await?;
+        Ok(())
+    }
 
-        if adventurer_count > 0 {
-            return Err(anyhow::anyhow!("Quest is already started"));
-        }
+    pub async fn transfer_ownership(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        transfer_quest_ownership_model: TransferQuestOwnershipModel,
+    ) -> DomainResult<i32> {
+        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
 
-        self.quest_ops_repository
-            .remove(quest_id, guild_commander_id)
+        quest.ensure_owned_by(guild_commander_id)?;
+
+        let quest_id = self
+            .quest_ops_repository
+            .transfer_ownership(
+                quest_id,
+                guild_commander_id,
+                transfer_quest_ownership_model.guild_commander_id,
+                AddQuestEventModel::new(
+                    QuestEventTypes::OwnershipTransferred,
+                    QuestEventActor::guild_commander(guild_commander_id),
+                )
+                .with_detail("from_guild_commander_id", guild_commander_id)
+                .with_detail(
+                    "to_guild_commander_id",
+                    transfer_quest_ownership_model.guild_commander_id,
+                ),
+            )
             .await?;
-        Ok(())
+        Ok(quest_id)
     }
 }
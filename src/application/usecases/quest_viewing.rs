@@ -1,10 +1,16 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    errors::DomainResult,
     repositories::quest_viewing::QuestViewingRepository,
-    value_objects::{board_checking_filter::BoardCheckingFilter, quest_model::QuestModel},
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter,
+        pagination::Page,
+        quest_adventurer_junction::CrewMemberModel,
+        quest_event_model::{QuestEventModel, QuestHistoryFilter},
+        quest_model::QuestModel,
+    },
 };
-use anyhow::{Ok, Result};
 pub struct QuestViewingUseCase<T>
 where
     T: QuestViewingRepository + Send + Sync,
@@ -22,31 +28,62 @@ where
         }
     }
 
-    pub async fn view_details(&self, quest_id: i32) -> Result<QuestModel> {
-        let result = self.quest_viewing_repository.view_details(quest_id).await?;
-
-        let adventurer_count = self
+    pub async fn view_details(&self, quest_id: i32) -> DomainResult<QuestModel> {
+        let (quest, adventurer_count) = self
             .quest_viewing_repository
-            .adventurer_counting_by_quest_id(quest_id)
+            .view_details_with_crew_count(quest_id)
             .await?;
 
-        Ok(result.to_model(adventurer_count))
+        Ok(quest.to_model(adventurer_count))
     }
 
-    pub async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestModel>> {
+    pub async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+    ) -> DomainResult<Page<QuestModel>> {
         let results = self.quest_viewing_repository.board_checking(filter).await?;
 
-        let mut quest_model: Vec<QuestModel> = Vec::new();
+        let total = self.quest_viewing_repository.board_counting(filter).await?;
 
-        for quest in results.into_iter() {
-            let adventurer_count = self
-                .quest_viewing_repository
-                .adventurer_counting_by_quest_id(quest.id)
-                .await?;
+        let quest_model = results
+            .iter()
+            .map(|(quest, adventurer_count)| quest.to_model(*adventurer_count))
+            .collect::<Vec<QuestModel>>();
 
-            quest_model.push(quest.to_model(adventurer_count));
-        }
+        Ok(Page::new(quest_model, total, filter.page_request()))
+    }
+
+    pub async fn crew_roster(&self, quest_id: i32) -> DomainResult<Vec<CrewMemberModel>> {
+        // ให้ quest ที่ไม่มีหรือถูกลบแล้วได้ 404 แทน list ว่าง
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let crew = self.quest_viewing_repository.crew_roster(quest_id).await?;
+
+        Ok(crew)
+    }
+
+    pub async fn history(
+        &self,
+        quest_id: i32,
+        filter: &QuestHistoryFilter,
+    ) -> DomainResult<Page<QuestEventModel>> {
+        self.quest_viewing_repository.view_details(quest_id).await?;
+
+        let quest_events = self
+            .quest_viewing_repository
+            .history(quest_id, filter)
+            .await?;
+
+        let total = self
+            .quest_viewing_repository
+            .history_counting(quest_id)
+            .await?;
+
+        let quest_event_models = quest_events
+            .iter()
+            .map(|quest_event| quest_event.to_model().without_moderation_details())
+            .collect::<Vec<QuestEventModel>>();
 
-        Ok(quest_model)
+        Ok(Page::new(quest_event_models, total, filter.page_request()))
     }
 }
@@ -1,9 +1,11 @@
 // use crate::config::config_model::DotEnvyConfig;
+use std::net::IpAddr;
+
 use anyhow::{Ok, Result};
 
 // อยู่ภายใต้ config เลยใช้ super
 use super::{
-    config_model::{AdventurerSecret, Database, DotEnvyConfig, GuildCommanderSecret, Server},
+    config_model::{Database, DotEnvyConfig, Jwt, LoginThrottle, Server},
     stage::Stage,
 };
 
@@ -20,36 +22,38 @@ pub fn load() -> Result<DotEnvyConfig> {
         url: std::env::var("DATABASE_URL")?,
     };
 
-    Ok(DotEnvyConfig { server, database })
-}
-
-pub fn get_stage() -> Stage {
-    dotenvy::dotenv().ok();
-
-    let stage_str = std::env::var("STAGE").unwrap_or_default();
-    Stage::try_from(&stage_str).unwrap_or_default()
-}
-
-pub fn get_adventurer_secret() -> Result<AdventurerSecret> {
-    dotenvy::dotenv().ok();
+    let jwt = Jwt {
+        keys_dir: std::env::var("JWT_KEYS_DIR")?,
+        active_key_id: std::env::var("JWT_ACTIVE_KEY_ID")?,
+    };
 
-    let secret = std::env::var("JWT_ADVENTURER_SECRET")?;
-    let refresh_secret = std::env::var("JWT_ADVENTURER_REFRESH_SECRET")?;
+    // ไม่ได้ตั้งไว้คือไม่นับต่อ IP และไม่เชื่อ header ของ proxy ไหนเลย
+    let login_throttle = LoginThrottle {
+        per_ip: std::env::var("LOGIN_THROTTLE_PER_IP")
+            .ok()
+            .map(|per_ip| per_ip.parse())
+            .transpose()?
+            .unwrap_or_default(),
+        trusted_proxies: std::env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+            .map(str::parse::<IpAddr>)
+            .collect::<Result<_, _>>()?,
+    };
 
-    Ok(AdventurerSecret {
-        secret,
-        refresh_secret,
+    Ok(DotEnvyConfig {
+        server,
+        database,
+        jwt,
+        login_throttle,
     })
 }
 
-pub fn get_guild_commander_secret() -> Result<GuildCommanderSecret> {
+pub fn get_stage() -> Stage {
     dotenvy::dotenv().ok();
 
-    let secret = std::env::var("JWT_GUILD_COMMANDER_SECRET")?;
-    let refresh_secret = std::env::var("JWT_GUILD_COMMANDER_REFRESH_SECRET")?;
-
-    Ok(GuildCommanderSecret {
-        secret,
-        refresh_secret,
-    })
+    let stage_str = std::env::var("STAGE").unwrap_or_default();
+    Stage::try_from(&stage_str).unwrap_or_default()
 }
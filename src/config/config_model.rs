@@ -1,7 +1,11 @@
+use std::net::IpAddr;
+
 #[derive(Debug, Clone)]
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
+    pub jwt: Jwt,
+    pub login_throttle: LoginThrottle,
 }
 
 #[derive(Debug, Clone)]
@@ -16,14 +20,17 @@ pub struct Database {
     pub url: String,
 }
 
+// private key ของ active_key_id ใช้ sign ส่วน public key ทุกไฟล์ใน keys_dir ใช้ verify
 #[derive(Debug, Clone)]
-pub struct AdventurerSecret {
-    pub secret: String,
-    pub refresh_secret: String,
+pub struct Jwt {
+    pub keys_dir: String,
+    pub active_key_id: String,
 }
 
-#[derive(Debug, Clone)]
-pub struct GuildCommanderSecret {
-    pub secret: String,
-    pub refresh_secret: String,
+// นับ login ผิดต่อ IP เฉพาะเมื่อเปิด per_ip ไว้ ถ้าอยู่หลัง proxy ต้องระบุ trusted_proxies
+// ไม่งั้นทุกคนจะได้ IP ของ proxy และโดน lock ไปพร้อมกัน
+#[derive(Debug, Clone, Default)]
+pub struct LoginThrottle {
+    pub per_ip: bool,
+    pub trusted_proxies: Vec<IpAddr>,
 }
@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{account_model::AccountModel, roles::Roles},
+    infrastructure::postgres::schema::accounts,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = accounts)]
+pub struct AccountEntity {
+    pub id: i32,
+    pub username: String,
+    pub password: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub suspended_at: Option<NaiveDateTime>,
+}
+
+impl AccountEntity {
+    pub fn to_model(&self, roles: Vec<Roles>) -> AccountModel {
+        AccountModel {
+            id: self.id,
+            username: self.username.clone(),
+            roles,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            suspended_at: self.suspended_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = accounts)]
+pub struct RegisterAccountEntity {
+    pub username: String,
+    pub password: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -1,23 +1,55 @@
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
-use crate::infrastructure::postgres::schema::guild_commanders;
+use crate::{
+    domain::value_objects::guild_commander_model::GuildCommanderModel,
+    infrastructure::postgres::schema::{accounts, guild_commanders},
+};
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
 #[diesel(table_name = guild_commanders)]
 pub struct GuildCommanderEntity {
     pub id: i32,
+    pub account_id: i32,
+    // username อยู่ที่ accounts ที่เดียว query ที่ select entity นี้ต้อง join accounts มาด้วย
+    #[diesel(select_expression = accounts::username)]
     pub username: String,
-    pub password: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl GuildCommanderEntity {
+    pub fn to_model(&self) -> GuildCommanderModel {
+        GuildCommanderModel {
+            id: self.id,
+            username: self.username.clone(),
+            display_name: self.display_name.clone(),
+            bio: self.bio.clone(),
+            avatar_url: self.avatar_url.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
 #[diesel(table_name = guild_commanders)]
 pub struct RegisterGuildCommanderEntity {
-    pub username: String,
-    pub password: String,
+    pub account_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
+
+#[derive(Debug, Clone, Queryable, AsChangeset)]
+#[diesel(table_name = guild_commanders)]
+pub struct UpdateGuildCommanderProfileEntity {
+    // None = ไม่แก้ field นั้น, Some(None) = ล้างเป็น NULL
+    pub display_name: Option<Option<String>>,
+    pub bio: Option<Option<String>>,
+    pub avatar_url: Option<Option<String>>,
+    pub updated_at: NaiveDateTime,
+}
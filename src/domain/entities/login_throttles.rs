@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::login_throttle_policy::LoginThrottlePolicy,
+    infrastructure::postgres::schema::login_throttles,
+};
+
+// นับการ login ผิดต่อ username หรือ IP
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = login_throttles)]
+pub struct LoginThrottleEntity {
+    pub throttle_key: String,
+    pub failed_attempts: i32,
+    pub locked_until: Option<NaiveDateTime>,
+    pub last_failed_at: NaiveDateTime,
+}
+
+impl LoginThrottleEntity {
+    pub fn next_failed_attempts(&self, policy: &LoginThrottlePolicy, now: NaiveDateTime) -> i32 {
+        if now - self.last_failed_at > policy.window {
+            1
+        } else {
+            self.failed_attempts + 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn throttle(failed_attempts: i32, last_failed_at: NaiveDateTime) -> LoginThrottleEntity {
+        LoginThrottleEntity {
+            throttle_key: "username:adventurer".to_string(),
+            failed_attempts,
+            locked_until: None,
+            last_failed_at,
+        }
+    }
+
+    #[test]
+    fn failures_inside_the_window_keep_counting() {
+        let policy = LoginThrottlePolicy::per_username();
+        let now = chrono::Utc::now().naive_utc();
+
+        assert_eq!(
+            throttle(3, now - Duration::minutes(5)).next_failed_attempts(&policy, now),
+            4
+        );
+        assert_eq!(
+            throttle(3, now - policy.window).next_failed_attempts(&policy, now),
+            4
+        );
+    }
+
+    #[test]
+    fn failures_after_the_window_start_over() {
+        let policy = LoginThrottlePolicy::per_username();
+        let now = chrono::Utc::now().naive_utc();
+
+        assert_eq!(
+            throttle(9, now - policy.window - Duration::seconds(1))
+                .next_failed_attempts(&policy, now),
+            1
+        );
+    }
+}
@@ -1,3 +1,8 @@
+pub mod accounts;
 pub mod adventurers;
 pub mod guild_commanders;
+pub mod login_throttles;
+pub mod moderation_actions;
+pub mod quest_events;
 pub mod quests;
+pub mod refresh_tokens;
@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::moderation_actions::ModerationActions,
+    infrastructure::postgres::schema::moderation_actions,
+};
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = moderation_actions)]
+pub struct AddModerationActionEntity {
+    pub admin_account_id: i32,
+    pub action: ModerationActions,
+    pub target_id: i32,
+    pub details: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
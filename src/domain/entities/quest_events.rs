@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        quest_event_model::{QuestEventActor, QuestEventModel},
+        quest_event_types::QuestEventTypes,
+        roles::Roles,
+    },
+    infrastructure::postgres::schema::quest_events,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_events)]
+pub struct QuestEventEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub event_type: QuestEventTypes,
+    pub actor_role: Roles,
+    pub actor_id: i32,
+    pub payload: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestEventEntity {
+    pub fn to_model(&self) -> QuestEventModel {
+        QuestEventModel {
+            id: self.id,
+            event_type: self.event_type,
+            actor: QuestEventActor {
+                role: self.actor_role,
+                id: self.actor_id,
+            },
+            payload: self.payload.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_events)]
+pub struct AddQuestEventEntity {
+    pub quest_id: i32,
+    pub event_type: QuestEventTypes,
+    pub actor_role: Roles,
+    pub actor_id: i32,
+    pub payload: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
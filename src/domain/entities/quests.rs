@@ -2,7 +2,13 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::quest_model::QuestModel, infrastructure::postgres::schema::quests,
+    domain::{
+        errors::{DomainError, DomainResult},
+        value_objects::{
+            crew_limits::CrewLimits, quest_model::QuestModel, quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::schema::quests,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -11,8 +17,11 @@ pub struct QuestEntity {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: QuestStatuses,
     pub guild_commander_id: i32,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub approval_required: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -23,13 +32,75 @@ impl QuestEntity {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
+            status: self.status,
             guild_commander_id: self.guild_commander_id,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            approval_required: self.approval_required,
             adventures_count,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
     }
+
+    pub fn crew_limits(&self) -> CrewLimits {
+        CrewLimits {
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+        }
+    }
+
+    pub fn ensure_owned_by(&self, guild_commander_id: i32) -> DomainResult<()> {
+        if self.guild_commander_id != guild_commander_id {
+            return Err(DomainError::Forbidden(
+                "Quest is not owned by this guild commander".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // แก้ไขหรือลบ quest ได้เฉพาะตอนยัง Open
+    pub fn ensure_modifiable(&self) -> DomainResult<()> {
+        if self.status != QuestStatuses::Open {
+            return Err(DomainError::InvalidStateTransition(format!(
+                "Quest can no longer be changed once it is {}",
+                self.status
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_joinable(&self, adventurer_count: i64) -> DomainResult<()> {
+        if !self.status.is_crew_editable() {
+            return Err(DomainError::Conflict("Quest is not joinable".to_string()));
+        }
+
+        if self.crew_limits().is_full(adventurer_count) {
+            return Err(DomainError::Conflict("Quest is full".to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_leaveable(&self) -> DomainResult<()> {
+        if !self.status.is_crew_editable() {
+            return Err(DomainError::Conflict("Quest is not leaveable".to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_crew_editable(&self) -> DomainResult<()> {
+        if !self.status.is_crew_editable() {
+            return Err(DomainError::Conflict(
+                "Quest crew can no longer be changed".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
@@ -37,8 +108,11 @@ impl QuestEntity {
 pub struct AddQuestEntity {
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: QuestStatuses,
     pub guild_commander_id: i32,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub approval_required: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -48,6 +122,18 @@ pub struct AddQuestEntity {
 pub struct EditQuestEntity {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub guild_commander_id: i32,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub approval_required: Option<bool>,
     pub updated_at: NaiveDateTime,
 }
+
+impl EditQuestEntity {
+    // รวมค่าที่แก้กับค่าเดิมของ quest แล้วตรวจว่ายังถูกต้อง
+    pub fn crew_limits(&self, current: CrewLimits) -> DomainResult<CrewLimits> {
+        CrewLimits::new(
+            self.min_crew.unwrap_or(current.min_crew),
+            self.max_crew.unwrap_or(current.max_crew),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::session_model::SessionModel,
+    infrastructure::postgres::schema::refresh_tokens,
+};
+
+// เก็บแค่ hash ของ refresh token ไม่เก็บ token จริง
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = refresh_tokens)]
+pub struct RefreshTokenEntity {
+    pub id: i32,
+    pub family_id: String,
+    pub token_hash: String,
+    pub account_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl RefreshTokenEntity {
+    // แถวล่าสุดของ family ที่ยังไม่ถูกใช้คือ session ที่ยัง active
+    pub fn to_session_model(&self, current_session_id: &str) -> SessionModel {
+        SessionModel {
+            session_id: self.family_id.clone(),
+            last_refreshed_at: self.created_at,
+            expires_at: self.expires_at,
+            current: self.family_id == current_session_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = refresh_tokens)]
+pub struct AddRefreshTokenEntity {
+    pub family_id: String,
+    pub token_hash: String,
+    pub account_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTokenRotation {
+    Rotated,
+    Revoked,
+    // token ที่ถูกหมุนไปแล้วถูกใช้ซ้ำ ทั้ง family ถูก revoke
+    ReuseDetected,
+}
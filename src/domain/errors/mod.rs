@@ -0,0 +1,79 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub type DomainResult<T> = std::result::Result<T, DomainError>;
+
+#[derive(Debug, Error)]
+pub enum DomainError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    InvalidStateTransition(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("Invalid input")]
+    InvalidFields(Vec<FieldError>),
+    #[error("{0}")]
+    TooManyRequests(String),
+    #[error(transparent)]
+    Infrastructure(#[from] anyhow::Error),
+}
+
+impl DomainError {
+    // ใช้เป็น code ใน response body ให้ client เอาไปเช็คได้
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::NotFound(_) => "not_found",
+            DomainError::Unauthorized(_) => "unauthorized",
+            DomainError::Forbidden(_) => "forbidden",
+            DomainError::Conflict(_) => "conflict",
+            DomainError::InvalidStateTransition(_) => "invalid_state_transition",
+            DomainError::Validation(_) | DomainError::InvalidFields(_) => "validation",
+            DomainError::TooManyRequests(_) => "too_many_requests",
+            DomainError::Infrastructure(_) => "infrastructure",
+        }
+    }
+}
+
+// error ราย field ให้ฟอร์มฝั่ง client แสดงข้างช่องที่กรอกผิดได้
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: String) -> Self {
+        Self {
+            field: field.to_string(),
+            message,
+        }
+    }
+}
+
+impl From<DieselError> for DomainError {
+    fn from(error: DieselError) -> Self {
+        match error {
+            DieselError::NotFound => DomainError::NotFound("Record not found".to_string()),
+            // ไม่ส่งข้อความของ Postgres (ชื่อ constraint, ค่าที่ซ้ำ) ออกไปถึง client
+            // repository ที่รู้บริบทควร map เป็นข้อความเฉพาะเอง
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                DomainError::Conflict("Resource already exists".to_string())
+            }
+            error => DomainError::Infrastructure(error.into()),
+        }
+    }
+}
+
+impl From<diesel::r2d2::PoolError> for DomainError {
+    fn from(error: diesel::r2d2::PoolError) -> Self {
+        DomainError::Infrastructure(error.into())
+    }
+}
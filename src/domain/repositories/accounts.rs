@@ -0,0 +1,19 @@
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::accounts::AccountEntity, errors::DomainResult, value_objects::roles::Roles,
+};
+
+#[async_trait]
+#[automock]
+pub trait AccountRepository {
+    async fn find_by_username(&self, username: String) -> DomainResult<AccountEntity>;
+    async fn find_by_id(&self, account_id: i32) -> DomainResult<AccountEntity>;
+    async fn roles(&self, account_id: i32) -> DomainResult<Vec<Roles>>;
+    // id ของ adventurer/guild commander profile ที่ยัง active, Admin ไม่มี profile เลยได้ account id
+    async fn find_role_profile_id(&self, account_id: i32, role: Roles)
+        -> DomainResult<Option<i32>>;
+    async fn change_password(&self, account_id: i32, password: String) -> DomainResult<()>;
+    async fn grant_role(&self, account_id: i32, role: Roles) -> DomainResult<()>;
+}
@@ -0,0 +1,40 @@
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::{accounts::AccountEntity, moderation_actions::AddModerationActionEntity},
+    errors::DomainResult,
+    value_objects::{
+        admin_model::AccountsFilter, quest_event_model::AddQuestEventModel,
+        quest_statuses::QuestStatuses, roles::Roles,
+    },
+};
+
+#[async_trait]
+#[automock]
+pub trait AdminRepository {
+    async fn accounts(
+        &self,
+        filter: &AccountsFilter,
+    ) -> DomainResult<Vec<(AccountEntity, Vec<Roles>)>>;
+    async fn accounts_counting(&self, filter: &AccountsFilter) -> DomainResult<i64>;
+    async fn suspend_account(
+        &self,
+        moderation_action: AddModerationActionEntity,
+    ) -> DomainResult<()>;
+    async fn unsuspend_account(
+        &self,
+        moderation_action: AddModerationActionEntity,
+    ) -> DomainResult<()>;
+    async fn restore_quest(
+        &self,
+        moderation_action: AddModerationActionEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn override_quest_status(
+        &self,
+        status: QuestStatuses,
+        moderation_action: AddModerationActionEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+}
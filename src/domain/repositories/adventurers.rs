@@ -1,11 +1,36 @@
-use crate::domain::entities::adventurers::{AdventurerEntity, RegisterAdventurerEntity};
-use anyhow::Result;
+use crate::domain::entities::{
+    accounts::RegisterAccountEntity,
+    adventurers::{AdventurerEntity, UpdateAdventurerProfileEntity},
+    guild_commanders::GuildCommanderEntity,
+    quests::QuestEntity,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::{
+    adventurer_model::JoinedQuestsFilter, quest_event_model::AddQuestEventModel,
+};
 use axum::async_trait;
 use mockall::automock;
 
 #[async_trait]
 #[automock]
 pub trait AdventurerRepository {
-    async fn register(&self, adventurer: RegisterAdventurerEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: String) -> Result<AdventurerEntity>;
+    async fn register(&self, account: RegisterAccountEntity) -> DomainResult<i32>;
+    async fn find_by_username(&self, username: String) -> DomainResult<AdventurerEntity>;
+    async fn find_by_id(&self, adventurer_id: i32) -> DomainResult<AdventurerEntity>;
+    async fn update_profile(
+        &self,
+        adventurer_id: i32,
+        update_adventurer_profile_entity: UpdateAdventurerProfileEntity,
+    ) -> DomainResult<AdventurerEntity>;
+    // quest_event ถูกบันทึกให้ทุก quest ที่ถอนตัวออกมา
+    async fn deactivate(
+        &self,
+        adventurer_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn joined_quests(
+        &self,
+        adventurer: &AdventurerEntity,
+        filter: &JoinedQuestsFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64, GuildCommanderEntity)>>;
 }
@@ -1,12 +1,49 @@
-use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::value_objects::quest_adventurer_junction::QuestAdventurerJunction;
+use crate::domain::{
+    errors::DomainResult,
+    value_objects::{
+        crew_membership_states::CrewMembershipStates,
+        quest_adventurer_junction::QuestAdventurerJunction, quest_event_model::AddQuestEventModel,
+    },
+};
 
 #[async_trait]
 #[automock]
 pub trait CrewSwitchboardRepository {
-    async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<CrewMembershipStates>;
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn kick(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn invite(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn approve(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn reject(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
 }
@@ -1,13 +1,39 @@
-use crate::domain::entities::guild_commanders::{
-    GuildCommanderEntity, RegisterGuildCommanderEntity,
+use crate::domain::entities::{
+    accounts::RegisterAccountEntity,
+    guild_commanders::{GuildCommanderEntity, UpdateGuildCommanderProfileEntity},
+    quests::QuestEntity,
+};
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::{
+    guild_commander_model::OwnedQuestsFilter, quest_event_model::AddQuestEventModel,
+    quest_statuses::QuestStatuses,
 };
-use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
 #[async_trait]
 #[automock]
 pub trait GuildCommanderRepository {
-    async fn register(&self, guild_commander: RegisterGuildCommanderEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: String) -> Result<GuildCommanderEntity>;
+    async fn register(&self, account: RegisterAccountEntity) -> DomainResult<i32>;
+    async fn find_by_id(&self, guild_commander_id: i32) -> DomainResult<GuildCommanderEntity>;
+    async fn update_profile(
+        &self,
+        guild_commander_id: i32,
+        update_guild_commander_profile_entity: UpdateGuildCommanderProfileEntity,
+    ) -> DomainResult<GuildCommanderEntity>;
+    // quest_event ถูกบันทึกให้ทุก quest ที่ถูกลบไปพร้อมกัน
+    async fn deactivate(
+        &self,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn owned_quests(
+        &self,
+        guild_commander_id: i32,
+        filter: &OwnedQuestsFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64)>>;
+    async fn owned_quest_status_counting(
+        &self,
+        guild_commander_id: i32,
+    ) -> DomainResult<Vec<(QuestStatuses, i64)>>;
 }
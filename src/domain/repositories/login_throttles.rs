@@ -0,0 +1,20 @@
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    errors::DomainResult, value_objects::login_throttle_policy::LoginThrottlePolicy,
+};
+
+#[async_trait]
+#[automock]
+pub trait LoginThrottleRepository {
+    async fn locked_until(&self, throttle_keys: Vec<String>)
+        -> DomainResult<Option<NaiveDateTime>>;
+    async fn record_failure(
+        &self,
+        throttle_key: String,
+        policy: LoginThrottlePolicy,
+    ) -> DomainResult<()>;
+    async fn reset(&self, throttle_key: String) -> DomainResult<()>;
+}
@@ -1,13 +1,38 @@
-use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
+use crate::domain::{
+    entities::quests::{AddQuestEntity, EditQuestEntity},
+    errors::DomainResult,
+    value_objects::quest_event_model::AddQuestEventModel,
+};
 
 #[async_trait]
 #[automock]
 pub trait QuestOpsRepository {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32>;
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32>;
-    async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()>;
+    async fn add(
+        &self,
+        add_quest_entity: AddQuestEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32>;
+    async fn edit(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        edit_quest_entity: EditQuestEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32>;
+    async fn remove(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()>;
+    async fn transfer_ownership(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        to_guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32>;
 }
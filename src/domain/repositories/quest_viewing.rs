@@ -1,15 +1,32 @@
-use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
 use crate::domain::{
-    entities::quests::QuestEntity, value_objects::board_checking_filter::BoardCheckingFilter,
+    entities::{quest_events::QuestEventEntity, quests::QuestEntity},
+    errors::DomainResult,
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, quest_adventurer_junction::CrewMemberModel,
+        quest_event_model::QuestHistoryFilter,
+    },
 };
 
 #[async_trait]
 #[automock]
 pub trait QuestViewingRepository {
-    async fn view_details(&self, quest_id: i32) -> Result<QuestEntity>;
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>>;
-    async fn adventurer_counting_by_quest_id(&self, quest_id: i32) -> Result<i64>;
+    async fn view_details(&self, quest_id: i32) -> DomainResult<QuestEntity>;
+    async fn view_details_with_crew_count(&self, quest_id: i32)
+        -> DomainResult<(QuestEntity, i64)>;
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64)>>;
+    async fn board_counting(&self, filter: &BoardCheckingFilter) -> DomainResult<i64>;
+    async fn adventurer_counting_by_quest_id(&self, quest_id: i32) -> DomainResult<i64>;
+    async fn crew_roster(&self, quest_id: i32) -> DomainResult<Vec<CrewMemberModel>>;
+    async fn history(
+        &self,
+        quest_id: i32,
+        filter: &QuestHistoryFilter,
+    ) -> DomainResult<Vec<QuestEventEntity>>;
+    async fn history_counting(&self, quest_id: i32) -> DomainResult<i64>;
 }
@@ -0,0 +1,23 @@
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity, RefreshTokenRotation},
+    errors::DomainResult,
+};
+
+#[async_trait]
+#[automock]
+pub trait RefreshTokenRepository {
+    async fn issue(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> DomainResult<i32>;
+    async fn rotate(
+        &self,
+        token_hash: String,
+        next_token_hash: String,
+    ) -> DomainResult<RefreshTokenRotation>;
+    async fn revoke_family(&self, token_hash: String) -> DomainResult<()>;
+    async fn is_session_active(&self, session_id: String, account_id: i32) -> DomainResult<bool>;
+    async fn active_sessions(&self, account_id: i32) -> DomainResult<Vec<RefreshTokenEntity>>;
+    async fn revoke_session(&self, session_id: String, account_id: i32) -> DomainResult<()>;
+    async fn revoke_all_sessions(&self, account_id: i32) -> DomainResult<usize>;
+}
@@ -0,0 +1,98 @@
+use crate::domain::errors::{DomainError, DomainResult, FieldError};
+
+const USERNAME_MIN_LENGTH: usize = 3;
+const USERNAME_MAX_LENGTH: usize = 32;
+const PASSWORD_MIN_LENGTH: usize = 8;
+// argon2 ใช้เวลาตามความยาว password เลยจำกัดไว้กันยิง password ยาวๆ
+const PASSWORD_MAX_LENGTH: usize = 128;
+
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
+
+// username เก็บเป็นตัวเล็กเสมอ ให้ unique แบบไม่สนตัวพิมพ์
+pub fn normalize_username(username: &str) -> String {
+    username.trim().to_lowercase()
+}
+
+pub fn validate_registration(username: &str, password: &str) -> DomainResult<()> {
+    let mut field_errors = Vec::new();
+
+    if let Some(message) = username_error(username) {
+        field_errors.push(FieldError::new("username", message));
+    }
+
+    if let Some(message) = password_error(password, username) {
+        field_errors.push(FieldError::new("password", message));
+    }
+
+    ensure_no_field_errors(field_errors)
+}
+
+pub fn validate_new_password(new_password: &str, username: &str) -> DomainResult<()> {
+    let field_errors = password_error(new_password, username)
+        .map(|message| FieldError::new("new_password", message))
+        .into_iter()
+        .collect();
+
+    ensure_no_field_errors(field_errors)
+}
+
+fn ensure_no_field_errors(field_errors: Vec<FieldError>) -> DomainResult<()> {
+    if field_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(DomainError::InvalidFields(field_errors))
+    }
+}
+
+fn username_error(username: &str) -> Option<String> {
+    let username = normalize_username(username);
+    let length = username.chars().count();
+
+    if !(USERNAME_MIN_LENGTH..=USERNAME_MAX_LENGTH).contains(&length) {
+        return Some(format!(
+            "Username must be between {} and {} characters",
+            USERNAME_MIN_LENGTH, USERNAME_MAX_LENGTH
+        ));
+    }
+
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+        return Some(
+            "Username may only contain letters, digits, underscores, dots and hyphens".to_string(),
+        );
+    }
+
+    if !username.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Some("Username must start with a letter or digit".to_string());
+    }
+
+    None
+}
+
+fn password_error(password: &str, username: &str) -> Option<String> {
+    let length = password.chars().count();
+
+    if !(PASSWORD_MIN_LENGTH..=PASSWORD_MAX_LENGTH).contains(&length) {
+        return Some(format!(
+            "Password must be between {} and {} characters",
+            PASSWORD_MIN_LENGTH, PASSWORD_MAX_LENGTH
+        ));
+    }
+
+    let lowercase_password = password.to_lowercase();
+
+    if lowercase_password == normalize_username(username) {
+        return Some("Password must not be the same as the username".to_string());
+    }
+
+    if COMMON_PASSWORDS
+        .lines()
+        .any(|common_password| common_password == lowercase_password)
+    {
+        return Some("Password is too common, choose a less guessable one".to_string());
+    }
+
+    None
+}
@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::roles::Roles;
+
+// ไม่มี password ออกไปกับ response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountModel {
+    pub id: i32,
+    pub username: String,
+    pub roles: Vec<Roles>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub suspended_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangePasswordModel {
+    pub old_password: String,
+    pub new_password: String,
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::{DomainError, DomainResult, FieldError};
+
+use super::{pagination::PageRequest, quest_statuses::QuestStatuses, roles::Roles};
+
+pub const DEFAULT_ACCOUNTS_LIMIT: i64 = 50;
+pub const MAX_ACCOUNTS_LIMIT: i64 = 200;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AccountsFilter {
+    pub username: Option<String>,
+    pub role: Option<Roles>,
+    pub suspended: Option<bool>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl AccountsFilter {
+    pub fn page_request(&self) -> PageRequest {
+        PageRequest::new(
+            self.page,
+            self.limit,
+            DEFAULT_ACCOUNTS_LIMIT,
+            MAX_ACCOUNTS_LIMIT,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModerationReasonModel {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverrideQuestStatusModel {
+    pub status: QuestStatuses,
+    pub reason: String,
+}
+
+impl OverrideQuestStatusModel {
+    // ข้ามกฎการเปลี่ยนสถานะทั้งหมด เลยต้องมีเหตุผลเก็บไว้เสมอ
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.reason.trim().is_empty() {
+            return Err(DomainError::InvalidFields(vec![FieldError::new(
+                "reason",
+                "Reason is required".to_string(),
+            )]));
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,14 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::adventurers::RegisterAdventurerEntity;
+use crate::domain::{
+    entities::{accounts::RegisterAccountEntity, adventurers::UpdateAdventurerProfileEntity},
+    errors::DomainResult,
+};
+
+use super::{
+    account_credentials, profile_fields, quest_model::QuestModel, quest_statuses::QuestStatuses,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterAdventurerModel {
@@ -9,12 +17,71 @@ pub struct RegisterAdventurerModel {
 }
 
 impl RegisterAdventurerModel {
-    pub fn to_entity(self) -> RegisterAdventurerEntity {
-        RegisterAdventurerEntity {
-            username: self.username,
+    pub fn validate(&self) -> DomainResult<()> {
+        account_credentials::validate_registration(&self.username, &self.password)
+    }
+
+    pub fn to_entity(self) -> RegisterAccountEntity {
+        RegisterAccountEntity {
+            username: account_credentials::normalize_username(&self.username),
             password: self.password,
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
 }
+
+// ไม่มี password ออกไปกับ response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAdventurerProfileModel {
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+impl UpdateAdventurerProfileModel {
+    pub fn to_entity(&self) -> DomainResult<UpdateAdventurerProfileEntity> {
+        let avatar_url = profile_fields::clearable(&self.avatar_url);
+
+        if let Some(Some(avatar_url)) = &avatar_url {
+            profile_fields::validate_avatar_url(avatar_url)?;
+        }
+
+        Ok(UpdateAdventurerProfileEntity {
+            display_name: profile_fields::clearable(&self.display_name),
+            bio: profile_fields::clearable(&self.bio),
+            avatar_url,
+            updated_at: chrono::Utc::now().naive_utc(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JoinedQuestsFilter {
+    pub status: Option<QuestStatuses>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCommanderModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinedQuestModel {
+    #[serde(flatten)]
+    pub quest: QuestModel,
+    pub guild_commander: QuestCommanderModel,
+}
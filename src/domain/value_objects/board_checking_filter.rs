@@ -1,9 +1,48 @@
 use serde::{Deserialize, Serialize};
 
-use super::quest_statuses::QuestStatuses;
+use super::{pagination::PageRequest, quest_statuses::QuestStatuses};
+
+pub const DEFAULT_BOARD_LIMIT: i64 = 20;
+pub const MAX_BOARD_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardSortField {
+    Name,
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    CrewCount,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BoardCheckingFilter {
     pub name: Option<String>,
     pub status: Option<QuestStatuses>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+    pub sort_by: Option<BoardSortField>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl BoardCheckingFilter {
+    pub fn page_request(&self) -> PageRequest {
+        PageRequest::new(self.page, self.limit, DEFAULT_BOARD_LIMIT, MAX_BOARD_LIMIT)
+    }
+
+    pub fn sort_by(&self) -> BoardSortField {
+        self.sort_by.unwrap_or_default()
+    }
+
+    pub fn sort_direction(&self) -> SortDirection {
+        self.sort_direction.unwrap_or_default()
+    }
 }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::{DomainError, DomainResult};
+
+pub const DEFAULT_MIN_CREW: i32 = 1;
+pub const DEFAULT_MAX_CREW: i32 = 4;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CrewLimits {
+    pub min_crew: i32,
+    pub max_crew: i32,
+}
+
+impl Default for CrewLimits {
+    fn default() -> Self {
+        Self {
+            min_crew: DEFAULT_MIN_CREW,
+            max_crew: DEFAULT_MAX_CREW,
+        }
+    }
+}
+
+impl CrewLimits {
+    pub fn new(min_crew: i32, max_crew: i32) -> DomainResult<Self> {
+        if min_crew < 1 {
+            return Err(DomainError::Validation(
+                "min_crew must be at least 1".to_string(),
+            ));
+        }
+
+        if max_crew < min_crew {
+            return Err(DomainError::Validation(
+                "max_crew must be greater than or equal to min_crew".to_string(),
+            ));
+        }
+
+        Ok(Self { min_crew, max_crew })
+    }
+
+    pub fn is_full(&self, crew_size: i64) -> bool {
+        crew_size >= self.max_crew as i64
+    }
+
+    pub fn can_depart(&self, crew_size: i64) -> bool {
+        crew_size >= self.min_crew as i64 && crew_size <= self.max_crew as i64
+    }
+}
@@ -0,0 +1,71 @@
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::domain::errors::DomainError;
+
+// สถานะของแถวใน quest_adventurer_junction มีแค่ Joined ที่นับเป็น crew
+#[derive(
+    Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Varchar)]
+pub enum CrewMembershipStates {
+    #[default]
+    Joined,
+    // adventurer ขอเข้า quest ที่ต้องให้ commander อนุมัติ
+    Pending,
+    // commander ชวน รอ adventurer กด join
+    Invited,
+}
+
+impl CrewMembershipStates {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrewMembershipStates::Joined => "Joined",
+            CrewMembershipStates::Pending => "Pending",
+            CrewMembershipStates::Invited => "Invited",
+        }
+    }
+}
+
+impl fmt::Display for CrewMembershipStates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CrewMembershipStates {
+    type Err = DomainError;
+
+    fn from_str(state: &str) -> Result<Self, Self::Err> {
+        match state {
+            "Joined" => Ok(CrewMembershipStates::Joined),
+            "Pending" => Ok(CrewMembershipStates::Pending),
+            "Invited" => Ok(CrewMembershipStates::Invited),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid crew membership state: {}",
+                state
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for CrewMembershipStates {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for CrewMembershipStates {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let state = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(state.parse()?)
+    }
+}
@@ -1,6 +1,16 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::guild_commanders::RegisterGuildCommanderEntity;
+use crate::domain::{
+    entities::{
+        accounts::RegisterAccountEntity, guild_commanders::UpdateGuildCommanderProfileEntity,
+    },
+    errors::DomainResult,
+};
+
+use super::{
+    account_credentials, profile_fields, quest_model::QuestModel, quest_statuses::QuestStatuses,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterGuildCommanderModel {
@@ -9,12 +19,88 @@ pub struct RegisterGuildCommanderModel {
 }
 
 impl RegisterGuildCommanderModel {
-    pub fn to_entity(self) -> RegisterGuildCommanderEntity {
-        RegisterGuildCommanderEntity {
-            username: self.username,
+    pub fn validate(&self) -> DomainResult<()> {
+        account_credentials::validate_registration(&self.username, &self.password)
+    }
+
+    pub fn to_entity(self) -> RegisterAccountEntity {
+        RegisterAccountEntity {
+            username: account_credentials::normalize_username(&self.username),
             password: self.password,
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildCommanderModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateGuildCommanderProfileModel {
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+impl UpdateGuildCommanderProfileModel {
+    pub fn to_entity(&self) -> DomainResult<UpdateGuildCommanderProfileEntity> {
+        let avatar_url = profile_fields::clearable(&self.avatar_url);
+
+        if let Some(Some(avatar_url)) = &avatar_url {
+            profile_fields::validate_avatar_url(avatar_url)?;
+        }
+
+        Ok(UpdateGuildCommanderProfileEntity {
+            display_name: profile_fields::clearable(&self.display_name),
+            bio: profile_fields::clearable(&self.bio),
+            avatar_url,
+            updated_at: chrono::Utc::now().naive_utc(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OwnedQuestsFilter {
+    pub status: Option<QuestStatuses>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuestStatusBreakdown {
+    pub open: i64,
+    pub in_journey: i64,
+    pub completed: i64,
+    pub failed: i64,
+}
+
+impl QuestStatusBreakdown {
+    pub fn new(status_counts: &[(QuestStatuses, i64)]) -> Self {
+        let mut breakdown = Self::default();
+
+        for (status, count) in status_counts {
+            match status {
+                QuestStatuses::Open => breakdown.open += count,
+                QuestStatuses::InJourney => breakdown.in_journey += count,
+                QuestStatuses::Completed => breakdown.completed += count,
+                QuestStatuses::Failed => breakdown.failed += count,
+            }
+        }
+
+        breakdown
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedQuestsModel {
+    pub quests: Vec<QuestModel>,
+    // นับจากทุก quest ที่เป็นเจ้าของ ไม่ขึ้นกับ status filter
+    pub status_breakdown: QuestStatusBreakdown,
+}
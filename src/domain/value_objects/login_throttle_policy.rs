@@ -0,0 +1,92 @@
+use chrono::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginThrottlePolicy {
+    pub max_attempts: i32,
+    pub base_lockout: Duration,
+    pub max_lockout: Duration,
+    // ความล้มเหลวที่ห่างจากครั้งก่อนเกิน window เริ่มนับใหม่
+    pub window: Duration,
+}
+
+impl LoginThrottlePolicy {
+    pub fn per_username() -> Self {
+        Self {
+            max_attempts: 5,
+            base_lockout: Duration::seconds(30),
+            max_lockout: Duration::minutes(15),
+            window: Duration::hours(1),
+        }
+    }
+
+    // IP เดียวอาจมีหลายคนใช้ร่วมกัน (NAT) เลยยอมให้ผิดได้มากกว่า
+    pub fn per_ip() -> Self {
+        Self {
+            max_attempts: 20,
+            base_lockout: Duration::seconds(30),
+            max_lockout: Duration::minutes(15),
+            window: Duration::hours(1),
+        }
+    }
+
+    // ครบ max_attempts แล้ว lock นานขึ้นเท่าตัวทุกครั้งที่ผิดซ้ำ
+    pub fn lockout(&self, failed_attempts: i32) -> Option<Duration> {
+        if failed_attempts < self.max_attempts {
+            return None;
+        }
+
+        let exponent = (failed_attempts - self.max_attempts).min(16) as u32;
+
+        Some(std::cmp::min(
+            self.base_lockout * 2_i32.pow(exponent),
+            self.max_lockout,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_lockout_below_max_attempts() {
+        let policy = LoginThrottlePolicy::per_username();
+
+        assert_eq!(policy.lockout(0), None);
+        assert_eq!(policy.lockout(policy.max_attempts - 1), None);
+    }
+
+    #[test]
+    fn lockout_starts_at_max_attempts_and_doubles() {
+        let policy = LoginThrottlePolicy::per_username();
+
+        assert_eq!(
+            policy.lockout(policy.max_attempts),
+            Some(policy.base_lockout)
+        );
+        assert_eq!(
+            policy.lockout(policy.max_attempts + 1),
+            Some(policy.base_lockout * 2)
+        );
+        assert_eq!(
+            policy.lockout(policy.max_attempts + 2),
+            Some(policy.base_lockout * 4)
+        );
+    }
+
+    #[test]
+    fn lockout_is_capped_at_max_lockout() {
+        let policy = LoginThrottlePolicy::per_username();
+
+        // 30 วินาที * 2^5 = 16 นาที เกิน 15 นาทีแล้ว
+        assert_eq!(
+            policy.lockout(policy.max_attempts + 4),
+            Some(Duration::minutes(8))
+        );
+        assert_eq!(
+            policy.lockout(policy.max_attempts + 5),
+            Some(policy.max_lockout)
+        );
+        assert_eq!(policy.lockout(i32::MAX), Some(policy.max_lockout));
+    }
+}
@@ -1,6 +1,19 @@
+pub mod account_credentials;
+pub mod account_model;
+pub mod admin_model;
 pub mod adventurer_model;
 pub mod board_checking_filter;
+pub mod crew_limits;
+pub mod crew_membership_states;
 pub mod guild_commander_model;
+pub mod login_throttle_policy;
+pub mod moderation_actions;
+pub mod pagination;
+pub mod profile_fields;
 pub mod quest_adventurer_junction;
+pub mod quest_event_model;
+pub mod quest_event_types;
 pub mod quest_model;
 pub mod quest_statuses;
+pub mod roles;
+pub mod session_model;
@@ -0,0 +1,69 @@
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::domain::errors::DomainError;
+
+// target_id ชี้ไปที่ account หรือ quest ตามชนิดของ action
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum ModerationActions {
+    SuspendAccount,
+    UnsuspendAccount,
+    RestoreQuest,
+    OverrideQuestStatus,
+}
+
+impl ModerationActions {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationActions::SuspendAccount => "SuspendAccount",
+            ModerationActions::UnsuspendAccount => "UnsuspendAccount",
+            ModerationActions::RestoreQuest => "RestoreQuest",
+            ModerationActions::OverrideQuestStatus => "OverrideQuestStatus",
+        }
+    }
+}
+
+impl fmt::Display for ModerationActions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ModerationActions {
+    type Err = DomainError;
+
+    fn from_str(action: &str) -> Result<Self, Self::Err> {
+        match action {
+            "SuspendAccount" => Ok(ModerationActions::SuspendAccount),
+            "UnsuspendAccount" => Ok(ModerationActions::UnsuspendAccount),
+            "RestoreQuest" => Ok(ModerationActions::RestoreQuest),
+            "OverrideQuestStatus" => Ok(ModerationActions::OverrideQuestStatus),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid moderation action: {}",
+                action
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for ModerationActions {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for ModerationActions {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let action = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(action.parse()?)
+    }
+}
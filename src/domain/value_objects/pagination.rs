@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+// กัน page * limit ล้น i64 (debug panic, release ได้ OFFSET ติดลบ)
+pub const MAX_PAGE: i64 = 100_000;
+
+// page/limit ที่ clamp แล้ว แต่ละ list กำหนด default และ max ของ limit เอง
+// filter ยังเก็บ page/limit เป็น field ของตัวเอง เพราะ Query ของ axum อ่านตัวเลขใน field ที่ serde(flatten) ไม่ได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub page: i64,
+    pub limit: i64,
+}
+
+impl PageRequest {
+    // page เริ่มที่ 1
+    pub fn new(page: Option<i64>, limit: Option<i64>, default_limit: i64, max_limit: i64) -> Self {
+        Self {
+            page: page.unwrap_or(1).clamp(1, MAX_PAGE),
+            limit: limit.unwrap_or(default_limit).clamp(1, max_limit),
+        }
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page - 1) * self.limit
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: i64,
+    pub limit: i64,
+    pub next_page: Option<i64>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: i64, page_request: PageRequest) -> Self {
+        let PageRequest { page, limit } = page_request;
+        let next_page = if page * limit < total {
+            Some(page + 1)
+        } else {
+            None
+        };
+
+        Self {
+            items,
+            total,
+            page,
+            limit,
+            next_page,
+        }
+    }
+}
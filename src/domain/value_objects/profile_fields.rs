@@ -0,0 +1,22 @@
+use crate::domain::errors::{DomainError, DomainResult};
+
+pub fn validate_avatar_url(avatar_url: &str) -> DomainResult<()> {
+    if !avatar_url.starts_with("https://") && !avatar_url.starts_with("http://") {
+        return Err(DomainError::Validation(
+            "Avatar URL must start with http:// or https://".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+// ไม่ส่ง field มา = ไม่แก้, ส่งสตริงว่างมา = ล้างค่าเป็น NULL
+pub fn clearable(value: &Option<String>) -> Option<Option<String>> {
+    value.as_ref().map(|value| {
+        if value.trim().is_empty() {
+            None
+        } else {
+            Some(value.clone())
+        }
+    })
+}
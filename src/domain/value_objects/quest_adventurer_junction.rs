@@ -1,13 +1,19 @@
 use crate::{
-    domain::entities::{adventurers::AdventurerEntity, quests::QuestEntity},
+    domain::{
+        entities::{adventurers::AdventurerEntity, quests::QuestEntity},
+        value_objects::crew_membership_states::CrewMembershipStates,
+    },
     infrastructure::postgres::schema::quest_adventurer_junction,
 };
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
-pub const MAX_ADVENTURERS_PER_QUEST: i64 = 4;
-
-#[derive(Debug, Clone, Serialize, Deserialize, Insertable, Queryable, Associations)]
+// joined_at และ state ใช้ default ของ database ตอน insert
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Insertable, Queryable, Identifiable, Associations,
+)]
+#[diesel(primary_key(quest_id, adventurer_id))]
 #[diesel(belongs_to(AdventurerEntity, foreign_key = adventurer_id))]
 #[diesel(belongs_to(QuestEntity, foreign_key = quest_id))]
 #[diesel(table_name = quest_adventurer_junction)]
@@ -15,3 +21,16 @@ pub struct QuestAdventurerJunction {
     pub quest_id: i32,
     pub adventurer_id: i32,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable)]
+pub struct CrewMemberModel {
+    pub adventurer_id: i32,
+    pub username: String,
+    pub joined_at: NaiveDateTime,
+    pub state: CrewMembershipStates,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteAdventurerModel {
+    pub username: String,
+}
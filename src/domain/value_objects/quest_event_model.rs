@@ -0,0 +1,117 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::domain::entities::quest_events::AddQuestEventEntity;
+
+use super::{pagination::PageRequest, quest_event_types::QuestEventTypes, roles::Roles};
+
+pub const DEFAULT_HISTORY_LIMIT: i64 = 50;
+pub const MAX_HISTORY_LIMIT: i64 = 200;
+// เหตุผลที่ admin ใส่ตอน moderate ดูได้เฉพาะ admin ไม่โชว์ใน history สาธารณะ
+const MODERATION_DETAILS: [&str; 1] = ["reason"];
+
+// id ของ profile ตาม role ส่วน Admin ใช้ account id
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuestEventActor {
+    pub role: Roles,
+    pub id: i32,
+}
+
+impl QuestEventActor {
+    pub fn adventurer(adventurer_id: i32) -> Self {
+        Self {
+            role: Roles::Adventurer,
+            id: adventurer_id,
+        }
+    }
+
+    pub fn guild_commander(guild_commander_id: i32) -> Self {
+        Self {
+            role: Roles::GuildCommander,
+            id: guild_commander_id,
+        }
+    }
+
+    pub fn admin(account_id: i32) -> Self {
+        Self {
+            role: Roles::Admin,
+            id: account_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestEventModel {
+    pub id: i32,
+    pub event_type: QuestEventTypes,
+    pub actor: QuestEventActor,
+    pub payload: Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestEventModel {
+    pub fn without_moderation_details(mut self) -> Self {
+        if let Value::Object(payload) = &mut self.payload {
+            for key in MODERATION_DETAILS {
+                payload.remove(key);
+            }
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct QuestHistoryFilter {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl QuestHistoryFilter {
+    pub fn page_request(&self) -> PageRequest {
+        PageRequest::new(
+            self.page,
+            self.limit,
+            DEFAULT_HISTORY_LIMIT,
+            MAX_HISTORY_LIMIT,
+        )
+    }
+}
+
+// usecase เป็นคนสร้าง event ส่วน repository บันทึกใน transaction เดียวกับการเปลี่ยนแปลง
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddQuestEventModel {
+    pub event_type: QuestEventTypes,
+    pub actor: QuestEventActor,
+    pub payload: Map<String, Value>,
+}
+
+impl AddQuestEventModel {
+    pub fn new(event_type: QuestEventTypes, actor: QuestEventActor) -> Self {
+        Self {
+            event_type,
+            actor,
+            payload: Map::new(),
+        }
+    }
+
+    // ค่าที่รู้ได้เฉพาะตอนอยู่ใน transaction (เช่นสถานะเดิม) repository ใส่เพิ่มเองได้
+    pub fn with_detail(mut self, key: &str, value: impl Serialize) -> Self {
+        self.payload.insert(
+            key.to_string(),
+            serde_json::to_value(value).unwrap_or(Value::Null),
+        );
+        self
+    }
+
+    pub fn to_entity(self, quest_id: i32) -> AddQuestEventEntity {
+        AddQuestEventEntity {
+            quest_id,
+            event_type: self.event_type,
+            actor_role: self.actor.role,
+            actor_id: self.actor.id,
+            payload: Value::Object(self.payload),
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
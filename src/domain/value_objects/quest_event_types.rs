@@ -0,0 +1,101 @@
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum QuestEventTypes {
+    Created,
+    Edited,
+    Removed,
+    OwnershipTransferred,
+    Joined,
+    Left,
+    Kicked,
+    Invited,
+    Approved,
+    Rejected,
+    Departed,
+    Completed,
+    Failed,
+    Restored,
+    StatusOverridden,
+}
+
+impl QuestEventTypes {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuestEventTypes::Created => "Created",
+            QuestEventTypes::Edited => "Edited",
+            QuestEventTypes::Removed => "Removed",
+            QuestEventTypes::OwnershipTransferred => "OwnershipTransferred",
+            QuestEventTypes::Joined => "Joined",
+            QuestEventTypes::Left => "Left",
+            QuestEventTypes::Kicked => "Kicked",
+            QuestEventTypes::Invited => "Invited",
+            QuestEventTypes::Approved => "Approved",
+            QuestEventTypes::Rejected => "Rejected",
+            QuestEventTypes::Departed => "Departed",
+            QuestEventTypes::Completed => "Completed",
+            QuestEventTypes::Failed => "Failed",
+            QuestEventTypes::Restored => "Restored",
+            QuestEventTypes::StatusOverridden => "StatusOverridden",
+        }
+    }
+}
+
+impl fmt::Display for QuestEventTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for QuestEventTypes {
+    type Err = DomainError;
+
+    fn from_str(event_type: &str) -> Result<Self, Self::Err> {
+        match event_type {
+            "Created" => Ok(QuestEventTypes::Created),
+            "Edited" => Ok(QuestEventTypes::Edited),
+            "Removed" => Ok(QuestEventTypes::Removed),
+            "OwnershipTransferred" => Ok(QuestEventTypes::OwnershipTransferred),
+            "Joined" => Ok(QuestEventTypes::Joined),
+            "Left" => Ok(QuestEventTypes::Left),
+            "Kicked" => Ok(QuestEventTypes::Kicked),
+            "Invited" => Ok(QuestEventTypes::Invited),
+            "Approved" => Ok(QuestEventTypes::Approved),
+            "Rejected" => Ok(QuestEventTypes::Rejected),
+            "Departed" => Ok(QuestEventTypes::Departed),
+            "Completed" => Ok(QuestEventTypes::Completed),
+            "Failed" => Ok(QuestEventTypes::Failed),
+            "Restored" => Ok(QuestEventTypes::Restored),
+            "StatusOverridden" => Ok(QuestEventTypes::StatusOverridden),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid quest event type: {}",
+                event_type
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for QuestEventTypes {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for QuestEventTypes {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let event_type = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(event_type.parse()?)
+    }
+}
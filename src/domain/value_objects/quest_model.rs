@@ -1,17 +1,26 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
+use crate::domain::{
+    entities::quests::{AddQuestEntity, EditQuestEntity},
+    errors::DomainResult,
+};
 
-use super::quest_statuses::QuestStatuses;
+use super::{
+    crew_limits::{CrewLimits, DEFAULT_MAX_CREW, DEFAULT_MIN_CREW},
+    quest_statuses::QuestStatuses,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: QuestStatuses,
     pub guild_commander_id: i32,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub approval_required: bool,
     pub adventures_count: i64,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
@@ -21,18 +30,29 @@ pub struct QuestModel {
 pub struct AddQuestModel {
     name: String,
     description: Option<String>,
+    min_crew: Option<i32>,
+    max_crew: Option<i32>,
+    approval_required: Option<bool>,
 }
 
 impl AddQuestModel {
-    pub fn to_entity(&self, guild_commander_id: i32) -> AddQuestEntity {
-        AddQuestEntity {
+    pub fn to_entity(&self, guild_commander_id: i32) -> DomainResult<AddQuestEntity> {
+        let crew_limits = CrewLimits::new(
+            self.min_crew.unwrap_or(DEFAULT_MIN_CREW),
+            self.max_crew.unwrap_or(DEFAULT_MAX_CREW),
+        )?;
+
+        Ok(AddQuestEntity {
             name: self.name.clone(),
             description: self.description.clone(),
             guild_commander_id,
-            status: QuestStatuses::Open.to_string(),
+            status: QuestStatuses::Open,
+            min_crew: crew_limits.min_crew,
+            max_crew: crew_limits.max_crew,
+            approval_required: self.approval_required.unwrap_or_default(),
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
-        }
+        })
     }
 }
 
@@ -41,15 +61,25 @@ impl AddQuestModel {
 pub struct EditQuestModel {
     name: Option<String>,
     description: Option<String>,
+    min_crew: Option<i32>,
+    max_crew: Option<i32>,
+    approval_required: Option<bool>,
 }
 
 impl EditQuestModel {
-    pub fn to_entity(&self, guild_commander_id: i32) -> EditQuestEntity {
+    pub fn to_entity(&self) -> EditQuestEntity {
         EditQuestEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            guild_commander_id,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            approval_required: self.approval_required,
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferQuestOwnershipModel {
+    pub guild_commander_id: i32,
+}
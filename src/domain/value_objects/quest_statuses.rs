@@ -1,7 +1,21 @@
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, io::Write, str::FromStr};
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+use crate::domain::errors::{DomainError, DomainResult};
+
+use super::crew_limits::CrewLimits;
+
+#[derive(
+    Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Varchar)]
 pub enum QuestStatuses {
     #[default]
     Open,
@@ -10,13 +24,90 @@ pub enum QuestStatuses {
     Failed,
 }
 
+// ตารางการเปลี่ยนสถานะที่อนุญาต (from, to)
+const TRANSITIONS: [(QuestStatuses, QuestStatuses); 4] = [
+    (QuestStatuses::Open, QuestStatuses::InJourney),
+    (QuestStatuses::Failed, QuestStatuses::InJourney),
+    (QuestStatuses::InJourney, QuestStatuses::Completed),
+    (QuestStatuses::InJourney, QuestStatuses::Failed),
+];
+
+impl QuestStatuses {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuestStatuses::Open => "Open",
+            QuestStatuses::InJourney => "InJourney",
+            QuestStatuses::Completed => "Completed",
+            QuestStatuses::Failed => "Failed",
+        }
+    }
+
+    pub fn can_transition_to(&self, next: QuestStatuses) -> bool {
+        TRANSITIONS.contains(&(*self, next))
+    }
+
+    pub fn transition_to(
+        &self,
+        next: QuestStatuses,
+        crew_size: i64,
+        crew_limits: CrewLimits,
+    ) -> DomainResult<QuestStatuses> {
+        if !self.can_transition_to(next) {
+            return Err(DomainError::InvalidStateTransition(format!(
+                "Quest cannot move from {} to {}",
+                self, next
+            )));
+        }
+
+        if next == QuestStatuses::InJourney && !crew_limits.can_depart(crew_size) {
+            return Err(DomainError::InvalidStateTransition(format!(
+                "Quest needs between {} and {} adventurers to depart",
+                crew_limits.min_crew, crew_limits.max_crew
+            )));
+        }
+
+        Ok(next)
+    }
+
+    // สถานะที่ยังให้ adventurer เข้าหรือออกจาก crew ได้
+    pub fn is_crew_editable(&self) -> bool {
+        matches!(self, QuestStatuses::Open | QuestStatuses::Failed)
+    }
+}
+
 impl fmt::Display for QuestStatuses {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            QuestStatuses::Open => write!(f, "Open"),
-            QuestStatuses::InJourney => write!(f, "In Journey"),
-            QuestStatuses::Completed => write!(f, "Completed"),
-            QuestStatuses::Failed => write!(f, "Failed"),
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for QuestStatuses {
+    type Err = DomainError;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "Open" => Ok(QuestStatuses::Open),
+            "InJourney" => Ok(QuestStatuses::InJourney),
+            "Completed" => Ok(QuestStatuses::Completed),
+            "Failed" => Ok(QuestStatuses::Failed),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid quest status: {}",
+                status
+            ))),
         }
     }
 }
+
+impl ToSql<Varchar, Pg> for QuestStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for QuestStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(status.parse()?)
+    }
+}
@@ -0,0 +1,63 @@
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::domain::errors::DomainError;
+
+// account หนึ่งถือได้หลาย role, Adventurer กับ GuildCommander มี profile ของตัวเอง ส่วน Admin ไม่มี
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum Roles {
+    Adventurer,
+    GuildCommander,
+    Admin,
+}
+
+impl Roles {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Roles::Adventurer => "Adventurer",
+            Roles::GuildCommander => "GuildCommander",
+            Roles::Admin => "Admin",
+        }
+    }
+}
+
+impl fmt::Display for Roles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Roles {
+    type Err = DomainError;
+
+    fn from_str(role: &str) -> Result<Self, Self::Err> {
+        match role {
+            "Adventurer" => Ok(Roles::Adventurer),
+            "GuildCommander" => Ok(Roles::GuildCommander),
+            "Admin" => Ok(Roles::Admin),
+            _ => Err(DomainError::Validation(format!("Invalid role: {}", role))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for Roles {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for Roles {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let role = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        Ok(role.parse()?)
+    }
+}
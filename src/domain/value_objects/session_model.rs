@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionModel {
+    pub session_id: String,
+    pub last_refreshed_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub current: bool,
+}
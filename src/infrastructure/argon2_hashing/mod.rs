@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use anyhow::{anyhow, Result};
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
@@ -24,3 +26,20 @@ pub fn verify(password: String, hash: String) -> Result<bool> {
         .verify_password(password.as_bytes(), &parsed_hash)
         .is_ok())
 }
+
+static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+// ใช้ตอนไม่พบ user ให้เสียเวลา verify เท่ากับกรณีรหัสผิด กันการเดา username จากเวลาตอบกลับ
+pub fn dummy_verify(password: String) -> Result<bool> {
+    let dummy_hash = match DUMMY_HASH.get() {
+        Some(dummy_hash) => dummy_hash,
+        None => {
+            let dummy_hash = hash(SaltString::generate(&mut OsRng).to_string())?;
+            DUMMY_HASH.get_or_init(|| dummy_hash)
+        }
+    };
+
+    verify(password, dummy_hash.clone())?;
+
+    Ok(false)
+}
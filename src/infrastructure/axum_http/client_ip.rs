@@ -0,0 +1,168 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts, HeaderMap, StatusCode},
+};
+
+use crate::config::config_model::LoginThrottle;
+
+// IP ที่ใช้นับ login ผิด เป็น None เมื่อไม่ได้เปิดนับต่อ IP
+// routes ของ authentication ใส่ config LoginThrottle ไว้ใน extensions
+#[derive(Debug, Clone)]
+pub struct LoginClientIp(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for LoginClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let login_throttle = parts
+            .extensions
+            .get::<LoginThrottle>()
+            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        if !login_throttle.per_ip {
+            return Ok(Self(None));
+        }
+
+        let ConnectInfo(peer_addr) = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+
+        let client_ip = client_ip(
+            peer_addr.ip(),
+            &parts.headers,
+            &login_throttle.trusted_proxies,
+        );
+
+        Ok(Self(Some(client_ip.to_string())))
+    }
+}
+
+// เชื่อ header เฉพาะเมื่อ request มาจาก proxy ที่ระบุไว้ ไล่จากขวาไปซ้ายแล้วเอา hop แรกที่ไม่ใช่ proxy ของเรา
+// ค่าซ้ายสุดเป็นอะไรก็ได้ที่ client ส่งมาเอง เลยใช้ไม่ได้
+pub fn client_ip(peer_ip: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpAddr]) -> IpAddr {
+    if !trusted_proxies.contains(&peer_ip) {
+        return peer_ip;
+    }
+
+    let mut forwarded_for = x_forwarded_for(headers);
+    if forwarded_for.is_empty() {
+        forwarded_for = forwarded(headers);
+    }
+
+    let mut client_ip = peer_ip;
+    for hop in forwarded_for.into_iter().rev() {
+        let Some(hop_ip) = hop else {
+            break;
+        };
+
+        client_ip = hop_ip;
+        if !trusted_proxies.contains(&hop_ip) {
+            break;
+        }
+    }
+
+    client_ip
+}
+
+fn x_forwarded_for(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|hop| hop.trim().parse().ok())
+        .collect()
+}
+
+// Forwarded: for=192.0.2.60;proto=http, for="[2001:db8::17]:4711"
+fn forwarded(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    headers
+        .get_all(header::FORWARDED)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|element| {
+            element.split(';').find_map(|pair| {
+                let (key, value) = pair.trim().split_once('=')?;
+                key.eq_ignore_ascii_case("for")
+                    .then(|| forwarded_node(value.trim().trim_matches('"')))
+            })
+        })
+        .collect()
+}
+
+fn forwarded_node(node: &str) -> Option<IpAddr> {
+    if let Some(ipv6) = node.strip_prefix('[') {
+        return ipv6.split(']').next()?.parse().ok();
+    }
+
+    node.split(':').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    const PROXY: &str = "10.0.0.1";
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    fn headers(name: &'static str, value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn untrusted_peer_cannot_spoof_its_ip() {
+        let headers = headers("x-forwarded-for", "203.0.113.9");
+
+        assert_eq!(
+            client_ip(ip("198.51.100.7"), &headers, &[ip(PROXY)]),
+            ip("198.51.100.7")
+        );
+    }
+
+    #[test]
+    fn trusted_proxy_takes_the_rightmost_untrusted_hop() {
+        let headers = headers("x-forwarded-for", "203.0.113.9, 198.51.100.7, 10.0.0.2");
+
+        assert_eq!(
+            client_ip(ip(PROXY), &headers, &[ip(PROXY), ip("10.0.0.2")]),
+            ip("198.51.100.7")
+        );
+    }
+
+    #[test]
+    fn trusted_proxy_falls_back_to_forwarded() {
+        let headers = headers(
+            "forwarded",
+            "for=\"[2001:db8::17]:4711\";proto=https, for=198.51.100.7:8080",
+        );
+
+        assert_eq!(
+            client_ip(ip(PROXY), &headers, &[ip(PROXY)]),
+            ip("198.51.100.7")
+        );
+    }
+
+    #[test]
+    fn trusted_proxy_without_header_is_the_client() {
+        assert_eq!(
+            client_ip(ip(PROXY), &HeaderMap::new(), &[ip(PROXY)]),
+            ip(PROXY)
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use axum::{http::StatusCode, response::IntoResponse};
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::infrastructure::jwt_authentication::jwt_keys;
 
 pub async fn not_found() -> impl IntoResponse {
     (StatusCode::NOT_FOUND, "Not Found").into_response()
@@ -7,3 +13,16 @@ pub async fn not_found() -> impl IntoResponse {
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, "OK").into_response()
 }
+
+// public key สำหรับ service อื่น verify token ของเราได้เองโดยไม่ต้องถือ private key
+pub async fn jwks() -> impl IntoResponse {
+    match jwt_keys::get() {
+        Ok(jwt_keys) => (
+            StatusCode::OK,
+            [(header::CACHE_CONTROL, "public, max-age=300")],
+            Json(jwt_keys.jwks()),
+        )
+            .into_response(),
+        Err(_) => (StatusCode::SERVICE_UNAVAILABLE, "JWT keys are not loaded").into_response(),
+    }
+}
@@ -0,0 +1,59 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::domain::errors::{DomainError, FieldError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+impl DomainError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            DomainError::NotFound(_) => StatusCode::NOT_FOUND,
+            DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
+            DomainError::Conflict(_) => StatusCode::CONFLICT,
+            DomainError::InvalidStateTransition(_)
+            | DomainError::Validation(_)
+            | DomainError::InvalidFields(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            DomainError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            DomainError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DomainError {
+    fn into_response(self) -> Response {
+        // ไม่ส่งรายละเอียดของ database หรือ config กลับไปให้ client
+        let message = match &self {
+            DomainError::Infrastructure(e) => {
+                error!("Infrastructure error: {:?}", e);
+                String::from("Internal server error")
+            }
+            e => e.to_string(),
+        };
+
+        let errors = match &self {
+            DomainError::InvalidFields(field_errors) => field_errors.clone(),
+            _ => Vec::new(),
+        };
+
+        let body = ErrorResponse {
+            code: self.code().to_string(),
+            message,
+            errors,
+        };
+
+        (self.status_code(), Json(body)).into_response()
+    }
+}
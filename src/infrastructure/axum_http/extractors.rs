@@ -0,0 +1,63 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts, http::StatusCode};
+
+// middleware ใส่ไว้ใน extensions หลังตรวจ token และ session แล้ว
+#[derive(Debug, Clone)]
+pub struct AuthenticatedAccount {
+    pub id: i32,
+    pub session_id: String,
+}
+
+// id คือ id ของ profile ตาม role ไม่ใช่ account id
+#[derive(Debug, Clone)]
+pub struct AuthenticatedAdventurer {
+    pub id: i32,
+    pub account_id: i32,
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticatedGuildCommander {
+    pub id: i32,
+    pub account_id: i32,
+    pub session_id: String,
+}
+
+// admin ไม่มี profile แยก id เลยเป็น account id
+#[derive(Debug, Clone)]
+pub struct AuthenticatedAdmin {
+    pub id: i32,
+    pub session_id: String,
+}
+
+// ถ้าไม่มีใน extensions แปลว่า route นั้นไม่ได้ผ่าน middleware ของ role นี้
+macro_rules! from_request_extensions {
+    ($($authenticated:ty),+ $(,)?) => {
+        $(
+            #[async_trait]
+            impl<S> FromRequestParts<S> for $authenticated
+            where
+                S: Send + Sync,
+            {
+                type Rejection = StatusCode;
+
+                async fn from_request_parts(
+                    parts: &mut Parts,
+                    _state: &S,
+                ) -> Result<Self, Self::Rejection> {
+                    parts
+                        .extensions
+                        .get::<Self>()
+                        .cloned()
+                        .ok_or(StatusCode::UNAUTHORIZED)
+                }
+            }
+        )+
+    };
+}
+
+from_request_extensions!(
+    AuthenticatedAccount,
+    AuthenticatedAdventurer,
+    AuthenticatedGuildCommander,
+    AuthenticatedAdmin,
+);
@@ -22,6 +22,8 @@ use super::default_routers;
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
     let app = Router::new()
         .fallback(default_routers::not_found)
+        .nest("/accounts", routers::accounts::routes(Arc::clone(&db_pool)))
+        .nest("/admin", routers::admin::routes(Arc::clone(&db_pool)))
         .nest(
             "/adventurers",
             routers::adventurers::routes(Arc::clone(&db_pool)),
@@ -48,9 +50,10 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
         )
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(Arc::clone(&db_pool), config.login_throttle.clone()),
         )
         .route("/health-check", get(default_routers::health_check))
+        .route("/.well-known/jwks.json", get(default_routers::jwks))
         .layer(TimeoutLayer::new(Duration::from_secs(
             config.server.timeout,
         )))
@@ -81,9 +84,13 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
 
     info!("Listening on: {}", addr);
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    // login throttling ต้องรู้ IP ของ client
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     Ok(())
 }
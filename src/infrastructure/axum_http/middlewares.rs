@@ -1,59 +1,157 @@
+use std::sync::Arc;
+
 use axum::{
-    extract::Request,
+    extract::{Request, State},
     http::{header, StatusCode},
     middleware::Next,
     response::Response,
 };
 
 use crate::{
-    config::config_loader::{get_adventurer_secret, get_guild_commander_secret},
-    infrastructure::jwt_authentication,
+    domain::{
+        errors::DomainError,
+        repositories::{accounts::AccountRepository, refresh_tokens::RefreshTokenRepository},
+        value_objects::roles::Roles,
+    },
+    infrastructure::{
+        axum_http::extractors::{
+            AuthenticatedAccount, AuthenticatedAdmin, AuthenticatedAdventurer,
+            AuthenticatedGuildCommander,
+        },
+        jwt_authentication::{self, jwt_model::TokenTypes},
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{accounts::AccountPostgres, refresh_tokens::RefreshTokenPostgres},
+        },
+    },
 };
 
+// account ไหนก็ได้ที่ login อยู่
+pub async fn accounts_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    authorize(&mut req, db_pool, None).await?;
+
+    Ok(next.run(req).await)
+}
+
 pub async fn adventurers_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
-            if let Some(token) = access_token {
-                if let Ok(secret) = get_adventurer_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret.secret, token) {
-                        if let Ok(adventurer_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(adventurer_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-            }
-        }
-    }
+    authorize(&mut req, db_pool, Some(Roles::Adventurer)).await?;
 
-    Err(StatusCode::UNAUTHORIZED)
+    Ok(next.run(req).await)
 }
 
 pub async fn guild_commanders_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    authorize(&mut req, db_pool, Some(Roles::GuildCommander)).await?;
+
+    Ok(next.run(req).await)
+}
+
+pub async fn admins_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
-            if let Some(token) = access_token {
-                if let Ok(secret) = get_guild_commander_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret.secret, token) {
-                        if let Ok(guild_commander_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(guild_commander_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
+    authorize(&mut req, db_pool, Some(Roles::Admin)).await?;
+
+    Ok(next.run(req).await)
+}
+
+// access token ต้องยังใช้ได้ และ session (refresh token family) ต้องยังไม่ถูก revoke
+// role เช็คกับ DB ทุกครั้งแทน roles ใน token เพราะ role อาจถูกถอดไปแล้วระหว่างที่ token ยังไม่หมดอายุ
+async fn authorize(
+    req: &mut Request,
+    db_pool: Arc<PgPoolSquad>,
+    required_role: Option<Roles>,
+) -> Result<(), StatusCode> {
+    let access_token = get_access_token(req).ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let claims = jwt_authentication::verify_token(access_token, TokenTypes::Access)
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let account_id = claims
+        .sub
+        .parse::<i32>()
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let is_session_active = RefreshTokenPostgres::new(Arc::clone(&db_pool))
+        .is_session_active(claims.sid.clone(), account_id)
+        .await
+        .map_err(|e| match e {
+            DomainError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::UNAUTHORIZED,
+        })?;
+
+    if !is_session_active {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    if let Some(role) = required_role {
+        let profile_id = AccountPostgres::new(db_pool)
+            .find_role_profile_id(account_id, role)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .ok_or(StatusCode::FORBIDDEN)?;
+
+        match role {
+            Roles::Adventurer => {
+                req.extensions_mut().insert(AuthenticatedAdventurer {
+                    id: profile_id,
+                    account_id,
+                    session_id: claims.sid.clone(),
+                });
+            }
+            Roles::GuildCommander => {
+                req.extensions_mut().insert(AuthenticatedGuildCommander {
+                    id: profile_id,
+                    account_id,
+                    session_id: claims.sid.clone(),
+                });
+            }
+            Roles::Admin => {
+                req.extensions_mut().insert(AuthenticatedAdmin {
+                    id: profile_id,
+                    session_id: claims.sid.clone(),
+                });
             }
         }
     }
 
-    Err(StatusCode::UNAUTHORIZED)
+    req.extensions_mut().insert(AuthenticatedAccount {
+        id: account_id,
+        session_id: claims.sid,
+    });
+
+    Ok(())
+}
+
+// Authorization: Bearer สำหรับ mobile/CLI มาก่อน ถ้าไม่มีค่อยดู cookie ของ browser
+fn get_access_token(req: &Request) -> Option<String> {
+    let bearer_token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .and_then(|authorization| authorization.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string());
+
+    if bearer_token.is_some() {
+        return bearer_token;
+    }
+
+    req.headers()
+        .get(header::COOKIE)
+        .and_then(|cookie_header| cookie_header.to_str().ok())
+        .and_then(|cookie_str| get_cookie_value(cookie_str, "act"))
 }
 
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
@@ -1,4 +1,7 @@
+pub mod client_ip;
 pub mod default_routers;
+pub mod error_responses;
+pub mod extractors;
 pub mod http_serve;
 pub mod middlewares;
 pub mod routers;
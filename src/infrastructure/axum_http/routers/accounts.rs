@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+    Json, Router,
+};
+
+use crate::{
+    application::usecases::accounts::AccountsUseCase,
+    domain::{
+        repositories::accounts::AccountRepository,
+        value_objects::{account_model::ChangePasswordModel, roles::Roles},
+    },
+    infrastructure::{
+        axum_http::{extractors::AuthenticatedAccount, middlewares::accounts_authorization},
+        postgres::{postgres_connection::PgPoolSquad, repositories::accounts::AccountPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let account_repository = AccountPostgres::new(Arc::clone(&db_pool));
+    let accounts_usecase = AccountsUseCase::new(Arc::new(account_repository));
+
+    Router::new()
+        .route("/me", get(profile))
+        .route("/me/password", patch(change_password))
+        .route("/me/roles/:role", post(grant_role))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            accounts_authorization,
+        ))
+        .with_state(Arc::new(accounts_usecase))
+}
+
+pub async fn profile<T>(
+    State(accounts_usecase): State<Arc<AccountsUseCase<T>>>,
+    AuthenticatedAccount { id: account_id, .. }: AuthenticatedAccount,
+) -> impl IntoResponse
+where
+    T: AccountRepository + Send + Sync,
+{
+    match accounts_usecase.profile(account_id).await {
+        Ok(account_model) => (StatusCode::OK, Json(account_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn change_password<T>(
+    State(accounts_usecase): State<Arc<AccountsUseCase<T>>>,
+    AuthenticatedAccount { id: account_id, .. }: AuthenticatedAccount,
+    Json(change_password_model): Json<ChangePasswordModel>,
+) -> impl IntoResponse
+where
+    T: AccountRepository + Send + Sync,
+{
+    match accounts_usecase
+        .change_password(account_id, change_password_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            String::from("Password changed successfully"),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+// token เดิมยังมี roles ชุดเก่า client ต้อง refresh token เพื่อให้ได้ roles ใหม่
+pub async fn grant_role<T>(
+    State(accounts_usecase): State<Arc<AccountsUseCase<T>>>,
+    AuthenticatedAccount { id: account_id, .. }: AuthenticatedAccount,
+    Path(role): Path<Roles>,
+) -> impl IntoResponse
+where
+    T: AccountRepository + Send + Sync,
+{
+    match accounts_usecase.grant_role(account_id, role).await {
+        Ok(account_model) => (StatusCode::CREATED, Json(account_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+    Json, Router,
+};
+
+use crate::{
+    application::usecases::admin::AdminUseCase,
+    domain::{
+        repositories::admin::AdminRepository,
+        value_objects::admin_model::{
+            AccountsFilter, ModerationReasonModel, OverrideQuestStatusModel,
+        },
+    },
+    infrastructure::{
+        axum_http::{extractors::AuthenticatedAdmin, middlewares::admins_authorization},
+        postgres::{postgres_connection::PgPoolSquad, repositories::admin::AdminPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let admin_repository = AdminPostgres::new(Arc::clone(&db_pool));
+    let admin_usecase = AdminUseCase::new(Arc::new(admin_repository));
+
+    Router::new()
+        .route("/accounts", get(accounts))
+        .route("/accounts/:account_id/suspend", post(suspend_account))
+        .route("/accounts/:account_id/unsuspend", post(unsuspend_account))
+        .route("/quests/:quest_id/restore", post(restore_quest))
+        .route("/quests/:quest_id/status", patch(override_quest_status))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            admins_authorization,
+        ))
+        .with_state(Arc::new(admin_usecase))
+}
+
+pub async fn accounts<T>(
+    State(admin_usecase): State<Arc<AdminUseCase<T>>>,
+    Query(filter): Query<AccountsFilter>,
+) -> impl IntoResponse
+where
+    T: AdminRepository + Send + Sync,
+{
+    match admin_usecase.accounts(&filter).await {
+        Ok(accounts_page) => (StatusCode::OK, Json(accounts_page)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+// reason ไม่บังคับ ส่ง body มาหรือไม่ก็ได้
+pub async fn suspend_account<T>(
+    State(admin_usecase): State<Arc<AdminUseCase<T>>>,
+    AuthenticatedAdmin {
+        id: admin_account_id,
+        ..
+    }: AuthenticatedAdmin,
+    Path(account_id): Path<i32>,
+    moderation_reason_model: Option<Json<ModerationReasonModel>>,
+) -> impl IntoResponse
+where
+    T: AdminRepository + Send + Sync,
+{
+    let Json(moderation_reason_model) = moderation_reason_model.unwrap_or_default();
+
+    match admin_usecase
+        .suspend_account(admin_account_id, account_id, moderation_reason_model.reason)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Account {} suspended successfully", account_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn unsuspend_account<T>(
+    State(admin_usecase): State<Arc<AdminUseCase<T>>>,
+    AuthenticatedAdmin {
+        id: admin_account_id,
+        ..
+    }: AuthenticatedAdmin,
+    Path(account_id): Path<i32>,
+    moderation_reason_model: Option<Json<ModerationReasonModel>>,
+) -> impl IntoResponse
+where
+    T: AdminRepository + Send + Sync,
+{
+    let Json(moderation_reason_model) = moderation_reason_model.unwrap_or_default();
+
+    match admin_usecase
+        .unsuspend_account(admin_account_id, account_id, moderation_reason_model.reason)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Account {} unsuspended successfully", account_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn restore_quest<T>(
+    State(admin_usecase): State<Arc<AdminUseCase<T>>>,
+    AuthenticatedAdmin {
+        id: admin_account_id,
+        ..
+    }: AuthenticatedAdmin,
+    Path(quest_id): Path<i32>,
+    moderation_reason_model: Option<Json<ModerationReasonModel>>,
+) -> impl IntoResponse
+where
+    T: AdminRepository + Send + Sync,
+{
+    let Json(moderation_reason_model) = moderation_reason_model.unwrap_or_default();
+
+    match admin_usecase
+        .restore_quest(admin_account_id, quest_id, moderation_reason_model.reason)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Quest {} restored successfully", quest_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn override_quest_status<T>(
+    State(admin_usecase): State<Arc<AdminUseCase<T>>>,
+    AuthenticatedAdmin {
+        id: admin_account_id,
+        ..
+    }: AuthenticatedAdmin,
+    Path(quest_id): Path<i32>,
+    Json(override_quest_status_model): Json<OverrideQuestStatusModel>,
+) -> impl IntoResponse
+where
+    T: AdminRepository + Send + Sync,
+{
+    let status = override_quest_status_model.status;
+
+    match admin_usecase
+        .override_quest_status(admin_account_id, quest_id, override_quest_status_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Quest {} is now {}", quest_id, status),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
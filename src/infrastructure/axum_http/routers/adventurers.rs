@@ -1,25 +1,60 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+    Json, Router,
+};
 
 use crate::{
-    application::usecases::adventurers::AdventurersUseCase,
+    application::usecases::{accounts::AccountsUseCase, adventurers::AdventurersUseCase},
     domain::{
         repositories::adventurers::AdventurerRepository,
-        value_objects::adventurer_model::RegisterAdventurerModel,
+        value_objects::adventurer_model::{
+            JoinedQuestsFilter, RegisterAdventurerModel, UpdateAdventurerProfileModel,
+        },
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::adventurers::AdventurerPostgres,
+    infrastructure::{
+        axum_http::{
+            extractors::AuthenticatedAdventurer, middlewares::adventurers_authorization, routers,
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{accounts::AccountPostgres, adventurers::AdventurerPostgres},
+        },
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let adventurer_repository = AdventurerPostgres::new(db_pool);
+    let adventurer_repository = AdventurerPostgres::new(Arc::clone(&db_pool));
     let adventurer_usecase = AdventurersUseCase::new(Arc::new(adventurer_repository));
 
+    // path เดิมของการเปลี่ยนรหัสผ่าน ย้ายไปเป็นของ account แล้วแต่ยังเปิดไว้ให้ client เก่า
+    let account_repository = AccountPostgres::new(Arc::clone(&db_pool));
+    let accounts_usecase = AccountsUseCase::new(Arc::new(account_repository));
+
+    let password_routes = Router::new()
+        .route("/me/password", patch(routers::accounts::change_password))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ))
+        .with_state(Arc::new(accounts_usecase));
+
+    // route_layer ครอบเฉพาะ route /me ที่ประกาศก่อนหน้า register ไม่ต้อง login
     Router::new()
+        .route("/me", get(profile).patch(update_profile).delete(deactivate))
+        .route("/me/quests", get(joined_quests))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ))
         .route("/", post(register))
         .with_state(Arc::new(adventurer_usecase))
+        .merge(password_routes)
 }
 
 pub async fn register<T>(
@@ -35,6 +70,78 @@ where
             format!("Register success:{}", adventurer_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn profile<T>(
+    State(adventurer_usecase): State<Arc<AdventurersUseCase<T>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
+) -> impl IntoResponse
+where
+    T: AdventurerRepository + Send + Sync,
+{
+    match adventurer_usecase.profile(adventurer_id).await {
+        Ok(adventurer_model) => (StatusCode::OK, Json(adventurer_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn joined_quests<T>(
+    State(adventurer_usecase): State<Arc<AdventurersUseCase<T>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
+    Query(filter): Query<JoinedQuestsFilter>,
+) -> impl IntoResponse
+where
+    T: AdventurerRepository + Send + Sync,
+{
+    match adventurer_usecase
+        .joined_quests(adventurer_id, &filter)
+        .await
+    {
+        Ok(joined_quests) => (StatusCode::OK, Json(joined_quests)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn update_profile<T>(
+    State(adventurer_usecase): State<Arc<AdventurersUseCase<T>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
+    Json(update_adventurer_profile_model): Json<UpdateAdventurerProfileModel>,
+) -> impl IntoResponse
+where
+    T: AdventurerRepository + Send + Sync,
+{
+    match adventurer_usecase
+        .update_profile(adventurer_id, update_adventurer_profile_model)
+        .await
+    {
+        Ok(adventurer_model) => (StatusCode::OK, Json(adventurer_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn deactivate<T>(
+    State(adventurer_usecase): State<Arc<AdventurersUseCase<T>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
+) -> impl IntoResponse
+where
+    T: AdventurerRepository + Send + Sync,
+{
+    match adventurer_usecase.deactivate(adventurer_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            String::from("Adventurer deactivated successfully"),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
@@ -1,237 +1,367 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::{header, HeaderMap, StatusCode},
-    response::IntoResponse,
-    routing::post,
-    Json, Router,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+    Extension, Json, Router,
 };
 use axum_extra::extract::cookie::{Cookie, CookieJar};
 use cookie::time::Duration;
 
 use crate::{
     application::usecases::authentication::AuthenticationUseCase,
-    config::{config_loader::get_stage, stage::Stage},
-    domain::repositories::{
-        adventurers::AdventurerRepository, guild_commanders::GuildCommanderRepository,
+    config::{config_loader::get_stage, config_model::LoginThrottle, stage::Stage},
+    domain::{
+        repositories::{
+            accounts::AccountRepository, login_throttles::LoginThrottleRepository,
+            refresh_tokens::RefreshTokenRepository,
+        },
+        value_objects::roles::Roles,
     },
     infrastructure::{
-        jwt_authentication::authentication_model::LoginModel,
+        axum_http::{
+            client_ip::LoginClientIp, extractors::AuthenticatedAccount,
+            middlewares::accounts_authorization,
+        },
+        jwt_authentication::{
+            authentication_model::{LoginModel, RefreshTokenModel},
+            jwt_model::Passport,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
-                adventurers::AdventurerPostgres, guild_commanders::GuildCommanderPostgres,
+                accounts::AccountPostgres, login_throttles::LoginThrottlePostgres,
+                refresh_tokens::RefreshTokenPostgres,
             },
         },
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let adventurer_repository = Arc::new(AdventurerPostgres::new(Arc::clone(&db_pool)));
-    let guild_commander_repository = Arc::new(GuildCommanderPostgres::new(Arc::clone(&db_pool)));
+pub fn routes(db_pool: Arc<PgPoolSquad>, login_throttle: LoginThrottle) -> Router {
+    let account_repository = Arc::new(AccountPostgres::new(Arc::clone(&db_pool)));
+    let refresh_token_repository = Arc::new(RefreshTokenPostgres::new(Arc::clone(&db_pool)));
+    let login_throttle_repository = Arc::new(LoginThrottlePostgres::new(Arc::clone(&db_pool)));
 
-    let authentication_usecase =
-        AuthenticationUseCase::new(adventurer_repository, guild_commander_repository);
+    let authentication_usecase = AuthenticationUseCase::new(
+        account_repository,
+        refresh_token_repository,
+        login_throttle_repository,
+    );
+
+    // path เดิมแยกตาม role ยังเปิดไว้ให้ client เก่า ใช้ handler เดียวกับ path ใหม่
+    let session_routes = Router::new()
+        .route("/sessions", get(sessions).delete(revoke_all_sessions))
+        .route("/sessions/:session_id", delete(revoke_session))
+        .route("/adventurers/logout", post(logout_session))
+        .route(
+            "/adventurers/sessions",
+            get(sessions).delete(revoke_all_sessions),
+        )
+        .route("/adventurers/sessions/:session_id", delete(revoke_session))
+        .route("/guild-commanders/logout", post(logout_session))
+        .route(
+            "/guild-commanders/sessions",
+            get(sessions).delete(revoke_all_sessions),
+        )
+        .route(
+            "/guild-commanders/sessions/:session_id",
+            delete(revoke_session),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            accounts_authorization,
+        ));
 
     Router::new()
+        .merge(session_routes)
+        .route("/login", post(login))
         .route("/adventurers/login", post(adventurer_login))
         .route("/guild-commanders/login", post(guild_commander_login))
-        .route("/adventurers/refresh-token", post(adventurer_refresh_token))
-        .route(
-            "/guild-commanders/refresh-token",
-            post(guild_commander_refresh_token),
-        )
+        .route("/refresh-token", post(refresh_token))
+        .route("/adventurers/refresh-token", post(refresh_token))
+        .route("/guild-commanders/refresh-token", post(refresh_token))
+        .route("/logout", post(logout))
+        .layer(Extension(login_throttle))
         .with_state(Arc::new(authentication_usecase))
 }
 
-pub async fn adventurer_login<T1, T2>(
-    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn login<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    LoginClientIp(client_ip): LoginClientIp,
+    headers: HeaderMap,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
-    match authentication_usecase.adventurer_login(login_model).await {
-        Ok(passport) => {
-            let mut access_token_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut refresh_token_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                access_token_cookie = access_token_cookie.secure(true);
-                refresh_token_cookie = refresh_token_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-            headers.append(
-                header::SET_COOKIE,
-                header::HeaderValue::from_str(&access_token_cookie.to_string()).unwrap(),
-            );
-            headers.append(
-                header::SET_COOKIE,
-                header::HeaderValue::from_str(&refresh_token_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, "Login successfully").into_response()
-        }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+    match authentication_usecase
+        .login(login_model, client_ip, None)
+        .await
+    {
+        Ok(passport) => passport_response(passport, wants_passport_json(&headers)),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn adventurer_refresh_token<T1, T2>(
-    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2>>>,
-    jar: CookieJar,
+pub async fn adventurer_login<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    LoginClientIp(client_ip): LoginClientIp,
+    headers: HeaderMap,
+    Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
-    if let Some(rft) = jar.get("rft") {
-        match authentication_usecase
-            .adventurer_refresh_token(rft.value().to_string())
-            .await
-        {
-            Ok(passport) => {
-                let mut access_token_cookie = Cookie::build(("act", passport.access_token.clone()))
-                    .path("/")
-                    .same_site(cookie::SameSite::Lax)
-                    .http_only(true)
-                    .max_age(Duration::days(14));
-
-                let mut refresh_token_cookie =
-                    Cookie::build(("rft", passport.refresh_token.clone()))
-                        .path("/")
-                        .same_site(cookie::SameSite::Lax)
-                        .http_only(true)
-                        .max_age(Duration::days(14));
-
-                if get_stage() == Stage::Production {
-                    access_token_cookie = access_token_cookie.secure(true);
-                    refresh_token_cookie = refresh_token_cookie.secure(true);
-                }
-
-                let mut headers = HeaderMap::new();
-                headers.append(
-                    header::SET_COOKIE,
-                    header::HeaderValue::from_str(&access_token_cookie.to_string()).unwrap(),
-                );
-                headers.append(
-                    header::SET_COOKIE,
-                    header::HeaderValue::from_str(&refresh_token_cookie.to_string()).unwrap(),
-                );
-
-                (StatusCode::OK, headers, "Login successfully").into_response()
-            }
-            Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
-        }
-    } else {
-        (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
+    match authentication_usecase
+        .login(login_model, client_ip, Some(Roles::Adventurer))
+        .await
+    {
+        Ok(passport) => passport_response(passport, wants_passport_json(&headers)),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn guild_commander_login<T1, T2>(
-    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn guild_commander_login<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    LoginClientIp(client_ip): LoginClientIp,
+    headers: HeaderMap,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
     match authentication_usecase
-        .guild_commander_login(login_model)
+        .login(login_model, client_ip, Some(Roles::GuildCommander))
         .await
     {
-        Ok(passport) => {
-            let mut access_token_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut refresh_token_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            if get_stage() == Stage::Production {
-                access_token_cookie = access_token_cookie.secure(true);
-                refresh_token_cookie = refresh_token_cookie.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-            headers.append(
-                header::SET_COOKIE,
-                header::HeaderValue::from_str(&access_token_cookie.to_string()).unwrap(),
-            );
-            headers.append(
-                header::SET_COOKIE,
-                header::HeaderValue::from_str(&refresh_token_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, "Login successfully").into_response()
-        }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Ok(passport) => passport_response(passport, wants_passport_json(&headers)),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn guild_commander_refresh_token<T1, T2>(
-    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2>>>,
+pub async fn refresh_token<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    headers: HeaderMap,
     jar: CookieJar,
+    refresh_token_model: Option<Json<RefreshTokenModel>>,
 ) -> impl IntoResponse
 where
-    T1: AdventurerRepository + Send + Sync,
-    T2: GuildCommanderRepository + Send + Sync,
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
 {
-    if let Some(rft) = jar.get("rft") {
-        match authentication_usecase
-            .guild_commander_refresh_token(rft.value().to_string())
-            .await
-        {
-            Ok(passport) => {
-                let mut access_token_cookie = Cookie::build(("act", passport.access_token.clone()))
-                    .path("/")
-                    .same_site(cookie::SameSite::Lax)
-                    .http_only(true)
-                    .max_age(Duration::days(14));
-
-                let mut refresh_token_cookie =
-                    Cookie::build(("rft", passport.refresh_token.clone()))
-                        .path("/")
-                        .same_site(cookie::SameSite::Lax)
-                        .http_only(true)
-                        .max_age(Duration::days(14));
-
-                if get_stage() == Stage::Production {
-                    access_token_cookie = access_token_cookie.secure(true);
-                    refresh_token_cookie = refresh_token_cookie.secure(true);
-                }
-
-                let mut headers = HeaderMap::new();
-                headers.append(
-                    header::SET_COOKIE,
-                    header::HeaderValue::from_str(&access_token_cookie.to_string()).unwrap(),
-                );
-                headers.append(
-                    header::SET_COOKIE,
-                    header::HeaderValue::from_str(&refresh_token_cookie.to_string()).unwrap(),
-                );
-
-                (StatusCode::OK, headers, "Login successfully").into_response()
-            }
-            Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+    let Some((refresh_token, as_json)) = extract_refresh_token(&headers, &jar, refresh_token_model)
+    else {
+        return (StatusCode::BAD_REQUEST, "Refresh token not found").into_response();
+    };
+
+    match authentication_usecase.refresh_token(refresh_token).await {
+        Ok(passport) => passport_response(passport, as_json),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn logout<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    refresh_token_model: Option<Json<RefreshTokenModel>>,
+) -> impl IntoResponse
+where
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    if let Some((refresh_token, _)) = extract_refresh_token(&headers, &jar, refresh_token_model) {
+        if let Err(e) = authentication_usecase.logout(refresh_token).await {
+            return e.into_response();
         }
-    } else {
-        (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
     }
+
+    (
+        StatusCode::OK,
+        expired_cookie_headers(),
+        "Logout successfully",
+    )
+        .into_response()
+}
+
+pub async fn logout_session<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    AuthenticatedAccount {
+        id: account_id,
+        session_id,
+    }: AuthenticatedAccount,
+) -> impl IntoResponse
+where
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match authentication_usecase
+        .logout_session(account_id, session_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            expired_cookie_headers(),
+            "Logout successfully",
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn sessions<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    AuthenticatedAccount {
+        id: account_id,
+        session_id,
+    }: AuthenticatedAccount,
+) -> impl IntoResponse
+where
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match authentication_usecase
+        .sessions(account_id, session_id)
+        .await
+    {
+        Ok(sessions) => (StatusCode::OK, Json(sessions)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke_session<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    AuthenticatedAccount { id: account_id, .. }: AuthenticatedAccount,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse
+where
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match authentication_usecase
+        .revoke_session(account_id, session_id)
+        .await
+    {
+        Ok(_) => (StatusCode::OK, "Session revoked successfully").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke_all_sessions<T1, T2, T3>(
+    State(authentication_usecase): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    AuthenticatedAccount { id: account_id, .. }: AuthenticatedAccount,
+) -> impl IntoResponse
+where
+    T1: AccountRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: LoginThrottleRepository + Send + Sync,
+{
+    match authentication_usecase.revoke_all_sessions(account_id).await {
+        Ok(revoked) => (
+            StatusCode::OK,
+            expired_cookie_headers(),
+            format!("Logged out of {} sessions", revoked),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+// client ที่ไม่ใช่ browser (mobile, CLI) ขอ passport เป็น JSON ผ่าน Accept header
+fn wants_passport_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/json"))
+}
+
+// refresh token ใน body มาก่อน cookie และถ้าส่งมาใน body ก็ตอบกลับเป็น JSON
+fn extract_refresh_token(
+    headers: &HeaderMap,
+    jar: &CookieJar,
+    refresh_token_model: Option<Json<RefreshTokenModel>>,
+) -> Option<(String, bool)> {
+    if let Some(Json(refresh_token_model)) = refresh_token_model {
+        return Some((refresh_token_model.refresh_token, true));
+    }
+
+    jar.get("rft")
+        .map(|rft| (rft.value().to_string(), wants_passport_json(headers)))
+}
+
+fn passport_response(passport: Passport, as_json: bool) -> Response {
+    if as_json {
+        return (StatusCode::OK, Json(passport)).into_response();
+    }
+
+    (
+        StatusCode::OK,
+        passport_cookie_headers(&passport),
+        "Login successfully",
+    )
+        .into_response()
+}
+
+fn passport_cookie_headers(passport: &Passport) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    for (name, value) in [
+        ("act", passport.access_token.clone()),
+        ("rft", passport.refresh_token.clone()),
+    ] {
+        let mut cookie = Cookie::build((name, value))
+            .path("/")
+            .same_site(cookie::SameSite::Lax)
+            .http_only(true)
+            .max_age(Duration::days(14));
+
+        if get_stage() == Stage::Production {
+            cookie = cookie.secure(true);
+        }
+
+        headers.append(
+            header::SET_COOKIE,
+            header::HeaderValue::from_str(&cookie.to_string()).unwrap(),
+        );
+    }
+
+    headers
+}
+
+// ส่ง cookie เดิมกลับไปแบบหมดอายุทันทีให้ browser ลบทิ้ง
+fn expired_cookie_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    for name in ["act", "rft"] {
+        let mut cookie = Cookie::build((name, ""))
+            .path("/")
+            .same_site(cookie::SameSite::Lax)
+            .http_only(true)
+            .max_age(Duration::ZERO);
+
+        if get_stage() == Stage::Production {
+            cookie = cookie.secure(true);
+        }
+
+        headers.append(
+            header::SET_COOKIE,
+            header::HeaderValue::from_str(&cookie.to_string()).unwrap(),
+        );
+    }
+
+    headers
 }
@@ -5,21 +5,32 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
-    Extension, Json, Router,
+    routing::{delete, patch, post},
+    Json, Router,
 };
 
 use crate::{
     application::usecases::crew_switchboard::CrewSwitchboardUseCase,
-    domain::repositories::{
-        crew_switchboard::CrewSwitchboardRepository, quest_viewing::QuestViewingRepository,
+    domain::{
+        repositories::{
+            adventurers::AdventurerRepository, crew_switchboard::CrewSwitchboardRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_adventurer_junction::InviteAdventurerModel,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::adventurers_authorization,
+        axum_http::{
+            extractors::{AuthenticatedAdventurer, AuthenticatedGuildCommander},
+            middlewares::{adventurers_authorization, guild_commanders_authorization},
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
-                crew_switchboard::CrewSwitchboardPostgres, quest_viewing::QuestViewingPostgres,
+                adventurers::AdventurerPostgres, crew_switchboard::CrewSwitchboardPostgres,
+                quest_viewing::QuestViewingPostgres,
             },
         },
     },
@@ -28,47 +39,182 @@ use crate::{
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_switchboard_repository = CrewSwitchboardPostgres::new(Arc::clone(&db_pool));
     let quest_viewing_repository = QuestViewingPostgres::new(Arc::clone(&db_pool));
+    let adventurer_repository = AdventurerPostgres::new(Arc::clone(&db_pool));
     let crew_switchboard_usecase = CrewSwitchboardUseCase::new(
         Arc::new(crew_switchboard_repository),
         Arc::new(quest_viewing_repository),
+        Arc::new(adventurer_repository),
     );
 
-    Router::new()
+    let adventurer_routes = Router::new()
         .route("/join/:quest_id", post(join))
         .route("/leave/:quest_id", delete(leave))
-        .route_layer(middleware::from_fn(adventurers_authorization))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            adventurers_authorization,
+        ));
+
+    let guild_commander_routes = Router::new()
+        .route("/kick/:quest_id/:adventurer_id", delete(kick))
+        .route("/invite/:quest_id", post(invite))
+        .route("/approve/:quest_id/:adventurer_id", patch(approve))
+        .route("/reject/:quest_id/:adventurer_id", delete(reject))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ));
+
+    Router::new()
+        .merge(adventurer_routes)
+        .merge(guild_commander_routes)
         .with_state(Arc::new(crew_switchboard_usecase))
 }
 
-pub async fn join<T1, T2>(
-    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
-    Extension(adventurer_id): Extension<i32>,
+pub async fn join<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
     Path(quest_id): Path<i32>, // รับ quest_id จาก path ชื่อต้องเหมือนกัน
 ) -> impl IntoResponse
 where
     T1: CrewSwitchboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
 {
     match crew_switchboard_usecase.join(quest_id, adventurer_id).await {
-        Ok(_) => (StatusCode::OK, String::from("Joined quest successfully")),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Ok(CrewMembershipStates::Pending) => (
+            StatusCode::ACCEPTED,
+            String::from("Join request sent, waiting for approval"),
+        )
+            .into_response(),
+        Ok(_) => (StatusCode::OK, String::from("Joined quest successfully")).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2>>>,
-    Extension(adventurer_id): Extension<i32>,
+pub async fn leave<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedAdventurer {
+        id: adventurer_id, ..
+    }: AuthenticatedAdventurer,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewSwitchboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
 {
     match crew_switchboard_usecase
         .leave(quest_id, adventurer_id)
         .await
     {
-        Ok(_) => (StatusCode::OK, String::from("Left quest successfully")),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Ok(_) => (StatusCode::OK, String::from("Left quest successfully")).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn kick<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .kick(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Adventurer {} removed from quest", adventurer_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn invite<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Path(quest_id): Path<i32>,
+    Json(invite_adventurer_model): Json<InviteAdventurerModel>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .invite(quest_id, guild_commander_id, invite_adventurer_model)
+        .await
+    {
+        Ok(adventurer_id) => (
+            StatusCode::CREATED,
+            format!("Adventurer {} invited to quest", adventurer_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn approve<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .approve(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request of adventurer {} approved", adventurer_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn reject<T1, T2, T3>(
+    State(crew_switchboard_usecase): State<Arc<CrewSwitchboardUseCase<T1, T2, T3>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwitchboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerRepository + Send + Sync,
+{
+    match crew_switchboard_usecase
+        .reject(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request of adventurer {} rejected", adventurer_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
@@ -1,15 +1,31 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+    Json, Router,
+};
 
 use crate::{
-    application::usecases::guild_commanders::GuildCommandersUseCase,
+    application::usecases::{accounts::AccountsUseCase, guild_commanders::GuildCommandersUseCase},
     domain::{
         repositories::guild_commanders::GuildCommanderRepository,
-        value_objects::guild_commander_model::RegisterGuildCommanderModel,
+        value_objects::guild_commander_model::{
+            OwnedQuestsFilter, RegisterGuildCommanderModel, UpdateGuildCommanderProfileModel,
+        },
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::guild_commanders::GuildCommanderPostgres,
+    infrastructure::{
+        axum_http::{
+            extractors::AuthenticatedGuildCommander, middlewares::guild_commanders_authorization,
+            routers,
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{accounts::AccountPostgres, guild_commanders::GuildCommanderPostgres},
+        },
     },
 };
 
@@ -17,9 +33,29 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let guild_commander_repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
     let guild_commander_usecase = GuildCommandersUseCase::new(Arc::new(guild_commander_repository));
 
+    // path เดิมของการเปลี่ยนรหัสผ่าน ย้ายไปเป็นของ account แล้วแต่ยังเปิดไว้ให้ client เก่า
+    let account_repository = AccountPostgres::new(Arc::clone(&db_pool));
+    let accounts_usecase = AccountsUseCase::new(Arc::new(account_repository));
+
+    let password_routes = Router::new()
+        .route("/me/password", patch(routers::accounts::change_password))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
+        .with_state(Arc::new(accounts_usecase));
+
+    // route_layer ครอบเฉพาะ route /me ที่ประกาศก่อนหน้า register ไม่ต้อง login
     Router::new()
+        .route("/me", get(profile).patch(update_profile).delete(deactivate))
+        .route("/me/quests", get(owned_quests))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
         .route("/", post(register))
         .with_state(Arc::new(guild_commander_usecase))
+        .merge(password_routes)
 }
 
 pub async fn register<T>(
@@ -38,6 +74,82 @@ where
             format!("Register success:{}", guild_commander_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn profile<T>(
+    State(guild_commander_usecase): State<Arc<GuildCommandersUseCase<T>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+) -> impl IntoResponse
+where
+    T: GuildCommanderRepository + Send + Sync,
+{
+    match guild_commander_usecase.profile(guild_commander_id).await {
+        Ok(guild_commander_model) => (StatusCode::OK, Json(guild_commander_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn owned_quests<T>(
+    State(guild_commander_usecase): State<Arc<GuildCommandersUseCase<T>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Query(filter): Query<OwnedQuestsFilter>,
+) -> impl IntoResponse
+where
+    T: GuildCommanderRepository + Send + Sync,
+{
+    match guild_commander_usecase
+        .owned_quests(guild_commander_id, &filter)
+        .await
+    {
+        Ok(owned_quests_model) => (StatusCode::OK, Json(owned_quests_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn update_profile<T>(
+    State(guild_commander_usecase): State<Arc<GuildCommandersUseCase<T>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Json(update_guild_commander_profile_model): Json<UpdateGuildCommanderProfileModel>,
+) -> impl IntoResponse
+where
+    T: GuildCommanderRepository + Send + Sync,
+{
+    match guild_commander_usecase
+        .update_profile(guild_commander_id, update_guild_commander_profile_model)
+        .await
+    {
+        Ok(guild_commander_model) => (StatusCode::OK, Json(guild_commander_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn deactivate<T>(
+    State(guild_commander_usecase): State<Arc<GuildCommandersUseCase<T>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+) -> impl IntoResponse
+where
+    T: GuildCommanderRepository + Send + Sync,
+{
+    match guild_commander_usecase.deactivate(guild_commander_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            String::from("Guild commander deactivated successfully"),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
@@ -6,7 +6,7 @@ use axum::{
     middleware,
     response::IntoResponse,
     routing::patch,
-    Extension, Router,
+    Router,
 };
 
 use crate::{
@@ -15,7 +15,9 @@ use crate::{
         journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            extractors::AuthenticatedGuildCommander, middlewares::guild_commanders_authorization,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
@@ -38,13 +40,19 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/in-journey/:quest_id", patch(in_journey))
         .route("/to-completed/:quest_id", patch(to_completed))
         .route("/to-failed/:quest_id", patch(to_failed))
-        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
         .with_state(Arc::new(journey_ledger_usecase))
 }
 
 pub async fn in_journey<T1, T2>(
     State(journey_ledger_usecase): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -55,20 +63,19 @@ where
         .in_journey(quest_id, guild_commander_id)
         .await
     {
-        Ok(quest_id) => (
-            StatusCode::OK,
-            format!("Quest {} is in journey", quest_id).into_response(),
-        ),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            format!("Error: {}", e).into_response(),
-        ),
+        Ok(quest_id) => {
+            (StatusCode::OK, format!("Quest {} is in journey", quest_id)).into_response()
+        }
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn to_completed<T1, T2>(
     State(journey_ledger_usecase): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -79,20 +86,19 @@ where
         .to_completed(quest_id, guild_commander_id)
         .await
     {
-        Ok(quest_id) => (
-            StatusCode::OK,
-            format!("Quest {} is in journey", quest_id).into_response(),
-        ),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            format!("Error: {}", e).into_response(),
-        ),
+        Ok(quest_id) => {
+            (StatusCode::OK, format!("Quest {} is completed", quest_id)).into_response()
+        }
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn to_failed<T1, T2>(
     State(journey_ledger_usecase): State<Arc<JourneyLedgerUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -103,13 +109,7 @@ where
         .to_failed(quest_id, guild_commander_id)
         .await
     {
-        Ok(quest_id) => (
-            StatusCode::OK,
-            format!("Quest {} is failed", quest_id).into_response(),
-        ),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            format!("Error: {}", e).into_response(),
-        ),
+        Ok(quest_id) => (StatusCode::OK, format!("Quest {} is failed", quest_id)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
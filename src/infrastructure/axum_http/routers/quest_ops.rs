@@ -6,17 +6,19 @@ use axum::{
     middleware,
     response::IntoResponse,
     routing::{delete, patch, post},
-    Extension, Json, Router,
+    Json, Router,
 };
 
 use crate::{
     application::usecases::quest_ops::QuestOpsUseCase,
     domain::{
         repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-        value_objects::quest_model::{AddQuestModel, EditQuestModel},
+        value_objects::quest_model::{AddQuestModel, EditQuestModel, TransferQuestOwnershipModel},
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            extractors::AuthenticatedGuildCommander, middlewares::guild_commanders_authorization,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{quest_ops::QuestOpsPostgres, quest_viewing::QuestViewingPostgres},
@@ -36,13 +38,20 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/", post(add))
         .route("/:quest_id", patch(edit))
         .route("/:quest_id", delete(remove))
-        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .route("/:quest_id/transfer-ownership", patch(transfer_ownership))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            guild_commanders_authorization,
+        ))
         .with_state(Arc::new(quest_ops_usecase))
 }
 
 pub async fn add<T1, T2>(
     State(quest_ops_usecase): State<Arc<QuestOpsUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Json(add_quest_model): Json<AddQuestModel>,
 ) -> impl IntoResponse
 where
@@ -55,15 +64,18 @@ where
     {
         Ok(quest_id) => {
             let response = format!("Quest created successfully: {}", quest_id);
-            (StatusCode::CREATED, response)
+            (StatusCode::CREATED, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn edit<T1, T2>(
     State(quest_ops_usecase): State<Arc<QuestOpsUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Path(quest_id): Path<i32>,
     Json(edit_quest_model): Json<EditQuestModel>,
 ) -> impl IntoResponse
@@ -77,15 +89,18 @@ where
     {
         Ok(quest_id) => {
             let response = format!("Quest updated successfully: {}", quest_id);
-            (StatusCode::OK, response)
+            (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn remove<T1, T2>(
     State(quest_ops_usecase): State<Arc<QuestOpsUseCase<T1, T2>>>,
-    Extension(guild_commander_id): Extension<i32>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -95,8 +110,33 @@ where
     match quest_ops_usecase.remove(quest_id, guild_commander_id).await {
         Ok(_) => {
             let response = format!("Remove quest successfully: {}", quest_id);
-            (StatusCode::OK, response)
+            (StatusCode::OK, response).into_response()
+        }
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn transfer_ownership<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUseCase<T1, T2>>>,
+    AuthenticatedGuildCommander {
+        id: guild_commander_id,
+        ..
+    }: AuthenticatedGuildCommander,
+    Path(quest_id): Path<i32>,
+    Json(transfer_quest_ownership_model): Json<TransferQuestOwnershipModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_usecase
+        .transfer_ownership(quest_id, guild_commander_id, transfer_quest_ownership_model)
+        .await
+    {
+        Ok(quest_id) => {
+            let response = format!("Quest ownership transferred successfully: {}", quest_id);
+            (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        Err(e) => e.into_response(),
     }
 }
@@ -2,7 +2,6 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     routing::get,
     Json, Router,
@@ -12,7 +11,9 @@ use crate::{
     application::usecases::quest_viewing::QuestViewingUseCase,
     domain::{
         repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::{self, BoardCheckingFilter},
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter, quest_event_model::QuestHistoryFilter,
+        },
     },
     infrastructure::postgres::{
         postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
@@ -26,6 +27,8 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
 
     Router::new()
         .route("/:quest_id", get(view_details))
+        .route("/:quest_id/crew", get(crew_roster))
+        .route("/:quest_id/history", get(history))
         .route("/board_checking", get(board_checking))
         .with_state(Arc::new(quest_viewing_usecase))
 }
@@ -39,7 +42,34 @@ where
 {
     match quest_viewing_usecase.view_details(quest_id).await {
         Ok(quest_model) => Json(quest_model).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn crew_roster<T>(
+    State(quest_viewing_usecase): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_usecase.crew_roster(quest_id).await {
+        Ok(crew) => Json(crew).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn history<T>(
+    State(quest_viewing_usecase): State<Arc<QuestViewingUseCase<T>>>,
+    Path(quest_id): Path<i32>,
+    filter: Query<QuestHistoryFilter>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_usecase.history(quest_id, &filter).await {
+        Ok(quest_history_page) => Json(quest_history_page).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -51,7 +81,7 @@ where
     T: QuestViewingRepository + Send + Sync,
 {
     match quest_viewing_usecase.board_checking(&filter).await {
-        Ok(quest_models) => Json(quest_models).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Ok(board_checking_page) => Json(board_checking_page).into_response(),
+        Err(e) => e.into_response(),
     }
 }
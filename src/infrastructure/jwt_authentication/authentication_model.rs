@@ -5,3 +5,8 @@ pub struct LoginModel {
     pub username: String,
     pub password: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenModel {
+    pub refresh_token: String,
+}
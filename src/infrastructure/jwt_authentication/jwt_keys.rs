@@ -0,0 +1,200 @@
+use std::{collections::HashMap, fs, path::Path, sync::OnceLock};
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::{
+    crypto,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, PublicKeyUse, RSAKeyParameters,
+    },
+    Algorithm, DecodingKey, EncodingKey,
+};
+use rsa::{
+    pkcs1::DecodeRsaPublicKey, pkcs8::DecodePublicKey, traits::PublicKeyParts, RsaPublicKey,
+};
+
+use crate::config::config_model::Jwt;
+
+static JWT_KEYS: OnceLock<JwtKeys> = OnceLock::new();
+
+// SubjectPublicKeyInfo ของ Ed25519 ยาวคงที่ คือ prefix 12 byte ตามด้วย public key 32 byte
+const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+pub struct SigningKey {
+    pub key_id: String,
+    pub algorithm: Algorithm,
+    pub encoding_key: EncodingKey,
+}
+
+pub struct VerificationKey {
+    pub algorithm: Algorithm,
+    pub decoding_key: DecodingKey,
+    pub jwk: Jwk,
+}
+
+impl VerificationKey {
+    fn from_public_pem(key_id: &str, public_pem: &[u8]) -> Result<Self> {
+        let pem = pem::parse(public_pem)?;
+        let der = pem.contents();
+
+        let (algorithm, key_algorithm, algorithm_parameters) =
+            if der.len() == 44 && der.starts_with(&ED25519_SPKI_PREFIX) {
+                (
+                    Algorithm::EdDSA,
+                    KeyAlgorithm::EdDSA,
+                    AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                        key_type: Default::default(),
+                        curve: EllipticCurve::Ed25519,
+                        x: URL_SAFE_NO_PAD.encode(&der[ED25519_SPKI_PREFIX.len()..]),
+                    }),
+                )
+            } else {
+                let public_key = match pem.tag() {
+                    "RSA PUBLIC KEY" => RsaPublicKey::from_pkcs1_der(der)?,
+                    _ => RsaPublicKey::from_public_key_der(der)?,
+                };
+
+                (
+                    Algorithm::RS256,
+                    KeyAlgorithm::RS256,
+                    AlgorithmParameters::RSA(RSAKeyParameters {
+                        key_type: Default::default(),
+                        n: URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+                        e: URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+                    }),
+                )
+            };
+
+        let jwk = Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(key_algorithm),
+                key_id: Some(key_id.to_string()),
+                ..Default::default()
+            },
+            algorithm: algorithm_parameters,
+        };
+
+        let decoding_key = DecodingKey::from_jwk(&jwk)?;
+
+        Ok(Self {
+            algorithm,
+            decoding_key,
+            jwk,
+        })
+    }
+}
+
+pub struct JwtKeys {
+    signing_key: SigningKey,
+    verification_keys: HashMap<String, VerificationKey>,
+}
+
+impl JwtKeys {
+    // keys_dir มี <kid>.pub.pem ของทุก key ที่ยังต้อง verify ได้ และ <kid>.pem ของ key ที่ใช้ sign
+    pub fn load(jwt: &Jwt) -> Result<Self> {
+        let keys_dir = Path::new(&jwt.keys_dir);
+
+        let mut verification_keys = HashMap::new();
+
+        for entry in fs::read_dir(keys_dir)
+            .with_context(|| format!("Failed to read JWT keys dir {}", keys_dir.display()))?
+        {
+            let path = entry?.path();
+
+            let Some(key_id) = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_suffix(".pub.pem"))
+            else {
+                continue;
+            };
+
+            let verification_key = VerificationKey::from_public_pem(key_id, &fs::read(&path)?)
+                .with_context(|| format!("Invalid public key {}", path.display()))?;
+
+            verification_keys.insert(key_id.to_string(), verification_key);
+        }
+
+        let active_verification_key =
+            verification_keys.get(&jwt.active_key_id).ok_or_else(|| {
+                anyhow!(
+                    "Public key of active key id {} not found in {}",
+                    jwt.active_key_id,
+                    keys_dir.display()
+                )
+            })?;
+
+        let private_key_path = keys_dir.join(format!("{}.pem", jwt.active_key_id));
+        let private_pem = fs::read(&private_key_path)
+            .with_context(|| format!("Failed to read {}", private_key_path.display()))?;
+
+        let encoding_key = match active_verification_key.algorithm {
+            Algorithm::EdDSA => EncodingKey::from_ed_pem(&private_pem)?,
+            _ => EncodingKey::from_rsa_pem(&private_pem)?,
+        };
+
+        // กัน private key กับ public key ที่ประกาศใน JWKS ไม่ใช่คู่เดียวกัน
+        let probe = jwt.active_key_id.as_bytes();
+        let signature = crypto::sign(probe, &encoding_key, active_verification_key.algorithm)?;
+
+        if !crypto::verify(
+            &signature,
+            probe,
+            &active_verification_key.decoding_key,
+            active_verification_key.algorithm,
+        )? {
+            bail!(
+                "Private key of {} does not match its public key",
+                jwt.active_key_id
+            );
+        }
+
+        Ok(Self {
+            signing_key: SigningKey {
+                key_id: jwt.active_key_id.clone(),
+                algorithm: active_verification_key.algorithm,
+                encoding_key,
+            },
+            verification_keys,
+        })
+    }
+
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing_key
+    }
+
+    pub fn verification_key(&self, key_id: &str) -> Option<&VerificationKey> {
+        self.verification_keys.get(key_id)
+    }
+
+    pub fn jwks(&self) -> JwkSet {
+        let mut keys = self
+            .verification_keys
+            .values()
+            .map(|verification_key| verification_key.jwk.clone())
+            .collect::<Vec<Jwk>>();
+
+        keys.sort_by(|a, b| a.common.key_id.cmp(&b.common.key_id));
+
+        JwkSet { keys }
+    }
+}
+
+// โหลดครั้งเดียวตอน start server แทนการอ่าน secret จาก env ทุก request
+pub fn init(jwt: &Jwt) -> Result<()> {
+    let jwt_keys = JwtKeys::load(jwt)?;
+
+    JWT_KEYS
+        .set(jwt_keys)
+        .map_err(|_| anyhow!("JWT keys are already initialized"))
+}
+
+pub fn get() -> Result<&'static JwtKeys> {
+    JWT_KEYS
+        .get()
+        .ok_or_else(|| anyhow!("JWT keys are not initialized"))
+}
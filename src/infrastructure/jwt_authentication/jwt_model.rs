@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::value_objects::roles::Roles;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
     pub access_token: String,
@@ -8,14 +10,24 @@ pub struct Passport {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
+    // account id
     pub sub: String,
-    pub role: Roles,
+    // role ตอนออก token ให้ service อื่นเช็คได้เอง ฝั่งเรายังเช็ค role กับ database ทุก request
+    pub roles: Vec<Roles>,
+    pub typ: TokenTypes,
+    pub iss: String,
+    pub aud: String,
     pub exp: usize,
     pub iat: usize,
+    // id เฉพาะของแต่ละ token ให้ hash ของ refresh token ไม่ซ้ำกันแม้ออกในวินาทีเดียวกัน
+    pub jti: String,
+    // family ของ refresh token ที่ออก token นี้ ใช้เช็คว่า session ยังไม่ถูก revoke
+    pub sid: String,
 }
 
+// แยก access กับ refresh token กันไม่ให้เอา refresh token มาใช้แทน access token
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum Roles {
-    Adventurer,
-    GuildCommander,
+pub enum TokenTypes {
+    Access,
+    Refresh,
 }
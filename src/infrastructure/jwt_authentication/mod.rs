@@ -1,26 +1,54 @@
 pub mod authentication_model;
+pub mod jwt_keys;
 pub mod jwt_model;
 
-use anyhow::Result;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use jwt_model::Claims;
+use anyhow::{anyhow, bail, Result};
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
+use jwt_model::{Claims, TokenTypes};
+use sha2::{Digest, Sha256};
 
-pub fn generate_token(secret: String, claims: &Claims) -> Result<String> {
-    let token = encode(
-        &Header::default(),
-        claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )?;
+pub const ISSUER: &str = "quests-tracker";
+pub const AUDIENCE: &str = "quests-tracker-api";
+
+pub fn generate_token(claims: &Claims) -> Result<String> {
+    let signing_key = jwt_keys::get()?.signing_key();
+
+    let mut header = Header::new(signing_key.algorithm);
+    header.kid = Some(signing_key.key_id.clone());
+
+    let token = encode(&header, claims, &signing_key.encoding_key)?;
 
     Ok(token)
 }
 
-pub fn verify_token(secret: String, token: String) -> Result<Claims> {
-    let result = decode::<Claims>(
-        &token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
-    )?;
+// เลือก public key จาก kid ใน header ให้ token ที่ sign ด้วย key เก่ายัง verify ได้ระหว่าง rotate
+pub fn verify_token(token: String, token_type: TokenTypes) -> Result<Claims> {
+    let key_id = decode_header(&token)?
+        .kid
+        .ok_or_else(|| anyhow!("Token has no key id"))?;
+
+    let verification_key = jwt_keys::get()?
+        .verification_key(&key_id)
+        .ok_or_else(|| anyhow!("Unknown key id"))?;
+
+    let mut validation = Validation::new(verification_key.algorithm);
+    validation.set_issuer(&[ISSUER]);
+    validation.set_audience(&[AUDIENCE]);
+    validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+
+    let result = decode::<Claims>(&token, &verification_key.decoding_key, &validation)?;
+
+    if result.claims.typ != token_type {
+        bail!("Invalid token type");
+    }
 
     Ok(result.claims)
 }
+
+pub fn generate_token_id() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,9 @@
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS quests_status_check;
+
+UPDATE
+    quests
+SET
+    "status" = 'In Journey'
+WHERE
+    "status" = 'InJourney';
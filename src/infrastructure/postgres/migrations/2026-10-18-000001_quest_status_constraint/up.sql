@@ -0,0 +1,11 @@
+UPDATE
+    quests
+SET
+    "status" = 'InJourney'
+WHERE
+    "status" = 'In Journey';
+
+ALTER TABLE
+    quests
+ADD
+    CONSTRAINT quests_status_check CHECK ("status" IN ('Open', 'InJourney', 'Completed', 'Failed'));
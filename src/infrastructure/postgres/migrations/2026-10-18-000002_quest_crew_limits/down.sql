@@ -0,0 +1,6 @@
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS quests_crew_limits_check;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS max_crew,
+    DROP COLUMN IF EXISTS min_crew;
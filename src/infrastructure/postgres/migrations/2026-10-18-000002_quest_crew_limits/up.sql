@@ -0,0 +1,14 @@
+ALTER TABLE
+    quests
+ADD
+    COLUMN min_crew INTEGER NOT NULL DEFAULT 1,
+ADD
+    COLUMN max_crew INTEGER NOT NULL DEFAULT 4;
+
+ALTER TABLE
+    quests
+ADD
+    CONSTRAINT quests_crew_limits_check CHECK (
+        min_crew >= 1
+        AND max_crew >= min_crew
+    );
@@ -0,0 +1,5 @@
+ALTER TABLE
+    adventurers DROP COLUMN IF EXISTS deleted_at,
+    DROP COLUMN IF EXISTS avatar_url,
+    DROP COLUMN IF EXISTS bio,
+    DROP COLUMN IF EXISTS display_name;
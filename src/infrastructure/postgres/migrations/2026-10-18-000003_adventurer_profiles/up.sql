@@ -0,0 +1,10 @@
+ALTER TABLE
+    adventurers
+ADD
+    COLUMN display_name VARCHAR(255),
+ADD
+    COLUMN bio TEXT,
+ADD
+    COLUMN avatar_url TEXT,
+ADD
+    COLUMN deleted_at TIMESTAMP;
@@ -0,0 +1,2 @@
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS joined_at;
@@ -0,0 +1,4 @@
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN joined_at TIMESTAMP NOT NULL DEFAULT now();
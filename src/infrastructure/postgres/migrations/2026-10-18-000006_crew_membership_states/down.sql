@@ -0,0 +1,8 @@
+ALTER TABLE
+    quest_adventurer_junction DROP CONSTRAINT IF EXISTS quest_adventurer_junction_state_check;
+
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS "state";
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS approval_required;
@@ -0,0 +1,14 @@
+ALTER TABLE
+    quests
+ADD
+    COLUMN approval_required BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN "state" VARCHAR(255) NOT NULL DEFAULT 'Joined';
+
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    CONSTRAINT quest_adventurer_junction_state_check CHECK ("state" IN ('Joined', 'Pending', 'Invited'));
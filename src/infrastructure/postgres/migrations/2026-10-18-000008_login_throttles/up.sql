@@ -0,0 +1,8 @@
+CREATE TABLE login_throttles (
+    "role" VARCHAR(255) NOT NULL,
+    throttle_key VARCHAR(512) NOT NULL,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    locked_until TIMESTAMP,
+    last_failed_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY ("role", throttle_key)
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS guild_commanders_username_lower_key;
+
+DROP INDEX IF EXISTS adventurers_username_lower_key;
@@ -0,0 +1,33 @@
+-- username ซ้ำกันแบบไม่สนตัวพิมพ์ไม่ได้ และใช้ index นี้ตอน login ด้วย lower(username)
+-- ถ้ามีแถวที่ชนกันอยู่แล้ว ให้ migration ล้มพร้อมรายชื่อ เพื่อให้ operator เลือกเองว่าจะเก็บ account ไหน
+DO $$
+DECLARE
+    collisions TEXT;
+BEGIN
+    SELECT string_agg(format('%s %s: %s', table_name, lowered, usernames), E'\n')
+    INTO collisions
+    FROM (
+        SELECT 'adventurers' AS table_name,
+               lower(username) AS lowered,
+               string_agg(format('#%s %s', id, username), ', ' ORDER BY id) AS usernames
+        FROM adventurers
+        GROUP BY lower(username)
+        HAVING count(*) > 1
+        UNION ALL
+        SELECT 'guild_commanders',
+               lower(username),
+               string_agg(format('#%s %s', id, username), ', ' ORDER BY id)
+        FROM guild_commanders
+        GROUP BY lower(username)
+        HAVING count(*) > 1
+    ) duplicated;
+
+    IF collisions IS NOT NULL THEN
+        RAISE EXCEPTION 'case-insensitive duplicate usernames must be resolved before this migration'
+            USING DETAIL = collisions;
+    END IF;
+END $$;
+
+CREATE UNIQUE INDEX adventurers_username_lower_key ON adventurers (lower(username));
+
+CREATE UNIQUE INDEX guild_commanders_username_lower_key ON guild_commanders (lower(username));
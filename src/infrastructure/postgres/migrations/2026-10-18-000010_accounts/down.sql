@@ -0,0 +1,59 @@
+DELETE FROM login_throttles;
+
+ALTER TABLE login_throttles DROP CONSTRAINT login_throttles_pkey;
+
+ALTER TABLE login_throttles ADD COLUMN "role" VARCHAR(255) NOT NULL;
+
+ALTER TABLE login_throttles ADD PRIMARY KEY ("role", throttle_key);
+
+DELETE FROM refresh_tokens;
+
+DROP INDEX IF EXISTS refresh_tokens_account_id_idx;
+
+ALTER TABLE refresh_tokens DROP CONSTRAINT refresh_tokens_account_id_fkey;
+
+ALTER TABLE refresh_tokens RENAME COLUMN account_id TO subject_id;
+
+ALTER TABLE refresh_tokens ADD COLUMN "role" VARCHAR(255) NOT NULL;
+
+CREATE INDEX refresh_tokens_subject_idx ON refresh_tokens ("role", subject_id);
+
+ALTER TABLE guild_commanders ADD COLUMN "password" VARCHAR(255);
+
+ALTER TABLE adventurers ADD COLUMN "password" VARCHAR(255);
+
+UPDATE guild_commanders SET "password" = accounts.password FROM accounts WHERE accounts.id = guild_commanders.account_id;
+
+UPDATE adventurers SET "password" = accounts.password FROM accounts WHERE accounts.id = adventurers.account_id;
+
+ALTER TABLE guild_commanders ALTER COLUMN "password" SET NOT NULL;
+
+ALTER TABLE adventurers ALTER COLUMN "password" SET NOT NULL;
+
+ALTER TABLE guild_commanders ADD COLUMN username VARCHAR(255);
+
+ALTER TABLE adventurers ADD COLUMN username VARCHAR(255);
+
+UPDATE guild_commanders SET username = accounts.username FROM accounts WHERE accounts.id = guild_commanders.account_id;
+
+UPDATE adventurers SET username = accounts.username FROM accounts WHERE accounts.id = adventurers.account_id;
+
+ALTER TABLE guild_commanders ALTER COLUMN username SET NOT NULL;
+
+ALTER TABLE adventurers ALTER COLUMN username SET NOT NULL;
+
+ALTER TABLE guild_commanders ADD CONSTRAINT guild_commanders_username_key UNIQUE (username);
+
+ALTER TABLE adventurers ADD CONSTRAINT adventurers_username_key UNIQUE (username);
+
+CREATE UNIQUE INDEX guild_commanders_username_lower_key ON guild_commanders (lower(username));
+
+CREATE UNIQUE INDEX adventurers_username_lower_key ON adventurers (lower(username));
+
+ALTER TABLE guild_commanders DROP COLUMN account_id;
+
+ALTER TABLE adventurers DROP COLUMN account_id;
+
+DROP TABLE IF EXISTS account_roles;
+
+DROP TABLE IF EXISTS accounts;
@@ -0,0 +1,110 @@
+-- account เก็บ username/password ครั้งเดียว ส่วน adventurers กับ guild_commanders กลายเป็น profile ของแต่ละ role
+CREATE TABLE accounts (
+    id SERIAL PRIMARY KEY,
+    username VARCHAR(255) NOT NULL,
+    "password" VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX accounts_username_lower_key ON accounts (lower(username));
+
+CREATE TABLE account_roles (
+    account_id INTEGER NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    "role" VARCHAR(255) NOT NULL,
+    granted_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (account_id, "role"),
+    CONSTRAINT account_roles_role_check CHECK ("role" IN ('Adventurer', 'GuildCommander', 'Admin'))
+);
+
+ALTER TABLE adventurers ADD COLUMN account_id INTEGER UNIQUE REFERENCES accounts (id);
+
+ALTER TABLE guild_commanders ADD COLUMN account_id INTEGER UNIQUE REFERENCES accounts (id);
+
+-- ย้ายของเดิมทีละแถว profile ที่ถูกปิดไปแล้วได้ account แต่ไม่ได้ role
+-- guild commander ที่ username ชนกับ adventurer และ password hash ตรงกันถือเป็นคนเดียวกัน เลยรวมเข้า account เดียว
+-- ถ้า hash ไม่ตรงกัน migration จะล้มพร้อมรายชื่อ ให้ operator ตัดสินเองว่าจะเปลี่ยนชื่อหรือรวมใคร
+DO $$
+DECLARE
+    profile RECORD;
+    new_account_id INTEGER;
+    collisions TEXT[] := ARRAY[]::TEXT[];
+BEGIN
+    FOR profile IN SELECT * FROM adventurers ORDER BY id LOOP
+        INSERT INTO accounts (username, "password", created_at, updated_at)
+        VALUES (profile.username, profile.password, profile.created_at, profile.updated_at)
+        RETURNING id INTO new_account_id;
+
+        UPDATE adventurers SET account_id = new_account_id WHERE id = profile.id;
+
+        IF profile.deleted_at IS NULL THEN
+            INSERT INTO account_roles (account_id, "role") VALUES (new_account_id, 'Adventurer');
+        END IF;
+    END LOOP;
+
+    FOR profile IN SELECT * FROM guild_commanders ORDER BY id LOOP
+        SELECT id INTO new_account_id FROM accounts WHERE lower(username) = lower(profile.username);
+
+        IF new_account_id IS NULL THEN
+            INSERT INTO accounts (username, "password", created_at, updated_at)
+            VALUES (profile.username, profile.password, profile.created_at, profile.updated_at)
+            RETURNING id INTO new_account_id;
+        ELSIF NOT EXISTS (
+            SELECT 1 FROM accounts WHERE id = new_account_id AND "password" = profile.password
+        ) THEN
+            collisions := collisions || format(
+                'guild_commanders #%s %s collides with adventurers #%s',
+                profile.id,
+                profile.username,
+                (SELECT id FROM adventurers WHERE account_id = new_account_id)
+            );
+            CONTINUE;
+        END IF;
+
+        UPDATE guild_commanders SET account_id = new_account_id WHERE id = profile.id;
+
+        IF profile.deleted_at IS NULL THEN
+            INSERT INTO account_roles (account_id, "role") VALUES (new_account_id, 'GuildCommander');
+        END IF;
+    END LOOP;
+
+    IF cardinality(collisions) > 0 THEN
+        RAISE EXCEPTION 'usernames shared by an adventurer and a guild commander with different passwords must be resolved before this migration'
+            USING DETAIL = array_to_string(collisions, E'\n');
+    END IF;
+END $$;
+
+ALTER TABLE adventurers ALTER COLUMN account_id SET NOT NULL;
+
+ALTER TABLE guild_commanders ALTER COLUMN account_id SET NOT NULL;
+
+ALTER TABLE adventurers DROP COLUMN "password";
+
+ALTER TABLE guild_commanders DROP COLUMN "password";
+
+-- username อยู่ที่ accounts ที่เดียว profile ไม่เก็บสำเนาไว้อีก
+ALTER TABLE adventurers DROP COLUMN username;
+
+ALTER TABLE guild_commanders DROP COLUMN username;
+
+-- session กับตัวนับ login ผิดผูกกับ account แทน role
+DELETE FROM refresh_tokens;
+
+DROP INDEX IF EXISTS refresh_tokens_subject_idx;
+
+ALTER TABLE refresh_tokens DROP COLUMN "role";
+
+ALTER TABLE refresh_tokens RENAME COLUMN subject_id TO account_id;
+
+ALTER TABLE refresh_tokens
+    ADD CONSTRAINT refresh_tokens_account_id_fkey FOREIGN KEY (account_id) REFERENCES accounts (id) ON DELETE CASCADE;
+
+CREATE INDEX refresh_tokens_account_id_idx ON refresh_tokens (account_id);
+
+DELETE FROM login_throttles;
+
+ALTER TABLE login_throttles DROP CONSTRAINT login_throttles_pkey;
+
+ALTER TABLE login_throttles DROP COLUMN "role";
+
+ALTER TABLE login_throttles ADD PRIMARY KEY (throttle_key);
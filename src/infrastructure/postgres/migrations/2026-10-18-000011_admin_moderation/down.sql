@@ -0,0 +1,3 @@
+DROP TABLE moderation_actions;
+
+ALTER TABLE accounts DROP COLUMN suspended_at;
@@ -0,0 +1,17 @@
+ALTER TABLE accounts ADD COLUMN suspended_at TIMESTAMP;
+
+-- บันทึกทุกการกระทำของ admin ว่าใครทำอะไรกับอะไร และเพราะอะไร
+CREATE TABLE moderation_actions (
+    id SERIAL PRIMARY KEY,
+    admin_account_id INTEGER NOT NULL REFERENCES accounts (id),
+    "action" VARCHAR(255) NOT NULL,
+    target_id INTEGER NOT NULL,
+    details TEXT,
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT moderation_actions_action_check CHECK (
+        "action" IN ('SuspendAccount', 'UnsuspendAccount', 'RestoreQuest', 'OverrideQuestStatus')
+    )
+);
+
+CREATE INDEX moderation_actions_target_idx ON moderation_actions ("action", target_id);
@@ -0,0 +1,26 @@
+-- ประวัติทุกการเปลี่ยนแปลงของ quest เขียนใน transaction เดียวกับการเปลี่ยนแปลงนั้น
+-- actor_id เป็น id ของ profile ตาม actor_role (Admin ใช้ account id)
+CREATE TABLE quest_events (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL REFERENCES quests (id),
+    event_type VARCHAR(255) NOT NULL,
+    actor_role VARCHAR(255) NOT NULL,
+    actor_id INTEGER NOT NULL,
+    payload JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT quest_events_event_type_check CHECK (
+        event_type IN (
+            'Created', 'Edited', 'Removed', 'OwnershipTransferred',
+            'Joined', 'Left', 'Kicked', 'Invited', 'Approved', 'Rejected',
+            'Departed', 'Completed', 'Failed', 'Restored', 'StatusOverridden'
+        )
+    ),
+    CONSTRAINT quest_events_actor_role_check CHECK (actor_role IN ('Adventurer', 'GuildCommander', 'Admin'))
+);
+
+CREATE INDEX quest_events_quest_id_idx ON quest_events (quest_id, created_at, id);
+
+-- quest ที่มีอยู่ก่อนได้แค่ event Created ย้อนหลัง ประวัติก่อนหน้านี้ไม่ได้เก็บไว้
+INSERT INTO quest_events (quest_id, event_type, actor_role, actor_id, payload, created_at)
+SELECT id, 'Created', 'GuildCommander', guild_commander_id, jsonb_build_object('name', name, 'backfilled', true), created_at
+FROM quests;
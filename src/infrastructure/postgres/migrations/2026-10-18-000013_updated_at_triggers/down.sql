@@ -0,0 +1,6 @@
+DROP TRIGGER accounts_set_updated_at ON accounts;
+DROP TRIGGER guild_commanders_set_updated_at ON guild_commanders;
+DROP TRIGGER adventurers_set_updated_at ON adventurers;
+DROP TRIGGER quests_set_updated_at ON quests;
+
+DROP FUNCTION set_updated_at();
@@ -0,0 +1,22 @@
+-- ทุก UPDATE ที่เปลี่ยนแถวจริงจะได้ updated_at ใหม่ ถ้า query ไม่ได้ตั้งค่าเองมา
+-- ใช้เวลา UTC ให้ตรงกับค่าที่ฝั่ง app เขียน (chrono::Utc::now().naive_utc())
+CREATE OR REPLACE FUNCTION set_updated_at() RETURNS trigger AS $$
+BEGIN
+    IF NEW IS DISTINCT FROM OLD AND NEW.updated_at IS NOT DISTINCT FROM OLD.updated_at THEN
+        NEW.updated_at := timezone('utc', now());
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER quests_set_updated_at BEFORE UPDATE ON quests
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER adventurers_set_updated_at BEFORE UPDATE ON adventurers
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER guild_commanders_set_updated_at BEFORE UPDATE ON guild_commanders
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+CREATE TRIGGER accounts_set_updated_at BEFORE UPDATE ON accounts
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
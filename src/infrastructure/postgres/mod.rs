@@ -1,3 +1,5 @@
 pub mod postgres_connection;
+pub mod postgres_migrations;
 pub mod repositories;
 pub mod schema;
+pub mod sql_functions;
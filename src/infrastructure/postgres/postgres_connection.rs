@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 use diesel::{
     r2d2::{ConnectionManager, Pool},
-    PgConnection,
+    Connection, PgConnection,
 };
 
+use crate::domain::errors::{DomainError, DomainResult};
+
 pub type PgPoolSquad = Pool<ConnectionManager<PgConnection>>;
 
 pub fn establish_connection(database_url: &str) -> Result<PgPoolSquad> {
@@ -11,3 +15,31 @@ pub fn establish_connection(database_url: &str) -> Result<PgPoolSquad> {
     let pool = Pool::builder().build(manager)?;
     Ok(pool)
 }
+
+// r2d2 กับ diesel เป็น sync ทั้งหมด เลยต้องย้ายไปทำบน blocking thread
+// ไม่ให้ไปบล็อก worker ของ tokio
+pub async fn with_connection<T, F>(db_pool: Arc<PgPoolSquad>, work: F) -> DomainResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut PgConnection) -> DomainResult<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let mut conn = db_pool.get()?;
+        work(&mut conn)
+    })
+    .await
+    .map_err(|e| DomainError::Infrastructure(e.into()))?
+}
+
+// unit of work: ทุก query ใน work ใช้ connection และ transaction เดียวกัน
+// ถ้า work คืน Err จะ rollback ทั้งหมด
+pub async fn unit_of_work<T, F>(db_pool: Arc<PgPoolSquad>, work: F) -> DomainResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut PgConnection) -> DomainResult<T> + Send + 'static,
+{
+    with_connection(db_pool, |conn| {
+        conn.transaction::<T, DomainError, _>(|conn| work(conn))
+    })
+    .await
+}
@@ -0,0 +1,63 @@
+use anyhow::{anyhow, Result};
+use diesel::{
+    migration::{MigrationSource, MigrationVersion},
+    pg::Pg,
+};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+use super::postgres_connection::PgPoolSquad;
+
+// migrations ถูก embed เข้าไปใน binary ตอน compile ไม่ต้องมีโฟลเดอร์ตอน deploy
+pub const MIGRATIONS: EmbeddedMigrations =
+    embed_migrations!("src/infrastructure/postgres/migrations");
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDrift {
+    // migrations ใน binary ที่ database ยังไม่ได้รัน
+    pub pending: Vec<String>,
+    // migrations ที่ database รันแล้วแต่ binary นี้ไม่รู้จัก
+    pub unknown: Vec<String>,
+}
+
+impl SchemaDrift {
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty() && self.unknown.is_empty()
+    }
+}
+
+pub fn run_pending_migrations(db_pool: &PgPoolSquad) -> Result<Vec<String>> {
+    let mut conn = db_pool.get()?;
+
+    let applied = conn
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to run migrations: {}", e))?;
+
+    Ok(applied.iter().map(|version| version.to_string()).collect())
+}
+
+pub fn check_schema_drift(db_pool: &PgPoolSquad) -> Result<SchemaDrift> {
+    let mut conn = db_pool.get()?;
+
+    let pending = conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to read pending migrations: {}", e))?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect::<Vec<String>>();
+
+    let embedded = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to read embedded migrations: {}", e))?
+        .iter()
+        .map(|migration| migration.name().version().as_owned())
+        .collect::<Vec<MigrationVersion<'static>>>();
+
+    let unknown = conn
+        .applied_migrations()
+        .map_err(|e| anyhow!("Failed to read applied migrations: {}", e))?
+        .into_iter()
+        .filter(|version| !embedded.contains(version))
+        .map(|version| version.to_string())
+        .collect::<Vec<String>>();
+
+    Ok(SchemaDrift { pending, unknown })
+}
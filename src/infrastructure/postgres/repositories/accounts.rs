@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use diesel::{
+    dsl::{exists, insert_into, select},
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            accounts::{AccountEntity, RegisterAccountEntity},
+            adventurers::RegisterAdventurerEntity,
+            guild_commanders::RegisterGuildCommanderEntity,
+        },
+        errors::{DomainError, DomainResult},
+        repositories::accounts::AccountRepository,
+        value_objects::{account_credentials::normalize_username, roles::Roles},
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        schema::{account_roles, accounts, adventurers, guild_commanders},
+        sql_functions::lower,
+    },
+};
+
+pub struct AccountPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AccountPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AccountRepository for AccountPostgres {
+    async fn find_by_username(&self, username: String) -> DomainResult<AccountEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = accounts::table
+                .filter(lower(accounts::username).eq(normalize_username(&username)))
+                .select(AccountEntity::as_select())
+                .first::<AccountEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn find_by_id(&self, account_id: i32) -> DomainResult<AccountEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = accounts::table
+                .filter(accounts::id.eq(account_id))
+                .select(AccountEntity::as_select())
+                .first::<AccountEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn roles(&self, account_id: i32) -> DomainResult<Vec<Roles>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = account_roles::table
+                .filter(account_roles::account_id.eq(account_id))
+                .order_by(account_roles::role.asc())
+                .select(account_roles::role)
+                .load::<Roles>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn find_role_profile_id(
+        &self,
+        account_id: i32,
+        role: Roles,
+    ) -> DomainResult<Option<i32>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let has_role = select(exists(
+                account_roles::table
+                    .filter(account_roles::account_id.eq(account_id))
+                    .filter(account_roles::role.eq(role)),
+            ))
+            .get_result::<bool>(conn)?;
+
+            if !has_role {
+                return Ok(None);
+            }
+
+            let result = match role {
+                Roles::Adventurer => adventurers::table
+                    .filter(adventurers::account_id.eq(account_id))
+                    .filter(adventurers::deleted_at.is_null())
+                    .select(adventurers::id)
+                    .first::<i32>(conn)
+                    .optional()?,
+                Roles::GuildCommander => guild_commanders::table
+                    .filter(guild_commanders::account_id.eq(account_id))
+                    .filter(guild_commanders::deleted_at.is_null())
+                    .select(guild_commanders::id)
+                    .first::<i32>(conn)
+                    .optional()?,
+                Roles::Admin => Some(account_id),
+            };
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn change_password(&self, account_id: i32, password: String) -> DomainResult<()> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            diesel::update(accounts::table)
+                .filter(accounts::id.eq(account_id))
+                .set((
+                    accounts::password.eq(password),
+                    accounts::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning(accounts::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn grant_role(&self, account_id: i32, role: Roles) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            grant_role(conn, account_id, role)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+// ใช้ร่วมกับ register ของ adventurers/guild_commanders ให้อยู่ใน transaction เดียวกัน
+pub(crate) fn insert_account(
+    conn: &mut PgConnection,
+    register_account_entity: RegisterAccountEntity,
+) -> DomainResult<i32> {
+    let account_id = insert_into(accounts::table)
+        .values(register_account_entity)
+        .returning(accounts::id)
+        .get_result::<i32>(conn)
+        .map_err(|e| match DomainError::from(e) {
+            DomainError::Conflict(_) => {
+                DomainError::Conflict("Username is already taken".to_string())
+            }
+            e => e,
+        })?;
+
+    Ok(account_id)
+}
+
+// เพิ่ม role ให้ account พร้อมสร้าง profile ของ role นั้น ถ้าเคย deactivate ไปแล้วก็เปิดกลับมาใช้ profile เดิม
+// คืน id ของ profile (Admin คืน account id)
+pub(crate) fn grant_role(
+    conn: &mut PgConnection,
+    account_id: i32,
+    role: Roles,
+) -> DomainResult<i32> {
+    let now = chrono::Utc::now().naive_utc();
+
+    let inserted = insert_into(account_roles::table)
+        .values((
+            account_roles::account_id.eq(account_id),
+            account_roles::role.eq(role),
+            account_roles::granted_at.eq(now),
+        ))
+        .on_conflict_do_nothing()
+        .execute(conn)?;
+
+    if inserted == 0 {
+        return Err(DomainError::Conflict(format!(
+            "Account already has the {} role",
+            role
+        )));
+    }
+
+    let profile_id = match role {
+        Roles::Adventurer => {
+            let reactivated = diesel::update(adventurers::table)
+                .filter(adventurers::account_id.eq(account_id))
+                .set((
+                    adventurers::deleted_at.eq(None::<chrono::NaiveDateTime>),
+                    adventurers::updated_at.eq(now),
+                ))
+                .returning(adventurers::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            match reactivated {
+                Some(adventurer_id) => adventurer_id,
+                None => insert_into(adventurers::table)
+                    .values(RegisterAdventurerEntity {
+                        account_id,
+                        created_at: now,
+                        updated_at: now,
+                    })
+                    .returning(adventurers::id)
+                    .get_result::<i32>(conn)?,
+            }
+        }
+        Roles::GuildCommander => {
+            let reactivated = diesel::update(guild_commanders::table)
+                .filter(guild_commanders::account_id.eq(account_id))
+                .set((
+                    guild_commanders::deleted_at.eq(None::<chrono::NaiveDateTime>),
+                    guild_commanders::updated_at.eq(now),
+                ))
+                .returning(guild_commanders::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            match reactivated {
+                Some(guild_commander_id) => guild_commander_id,
+                None => insert_into(guild_commanders::table)
+                    .values(RegisterGuildCommanderEntity {
+                        account_id,
+                        created_at: now,
+                        updated_at: now,
+                    })
+                    .returning(guild_commanders::id)
+                    .get_result::<i32>(conn)?,
+            }
+        }
+        Roles::Admin => account_id,
+    };
+
+    Ok(profile_id)
+}
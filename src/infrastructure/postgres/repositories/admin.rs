@@ -0,0 +1,293 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::async_trait;
+use diesel::{
+    dsl::{exists, insert_into},
+    pg::Pg,
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::{accounts::AccountEntity, moderation_actions::AddModerationActionEntity},
+        errors::{DomainError, DomainResult},
+        repositories::admin::AdminRepository,
+        value_objects::{
+            admin_model::AccountsFilter, quest_event_model::AddQuestEventModel,
+            quest_statuses::QuestStatuses, roles::Roles,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        repositories::quest_events::record_quest_event,
+        schema::{
+            account_roles, accounts, guild_commanders, moderation_actions, quests, refresh_tokens,
+        },
+    },
+};
+
+pub struct AdminPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AdminPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn filtered_accounts(filter: &AccountsFilter) -> accounts::BoxedQuery<'_, Pg> {
+    let mut query = accounts::table.into_boxed();
+
+    if let Some(username) = &filter.username {
+        query = query.filter(accounts::username.ilike(format!("%{}%", username.trim())));
+    }
+
+    if let Some(role) = filter.role {
+        query = query.filter(exists(
+            account_roles::table
+                .filter(account_roles::account_id.eq(accounts::id))
+                .filter(account_roles::role.eq(role)),
+        ));
+    }
+
+    match filter.suspended {
+        Some(true) => query = query.filter(accounts::suspended_at.is_not_null()),
+        Some(false) => query = query.filter(accounts::suspended_at.is_null()),
+        None => {}
+    }
+
+    query
+}
+
+fn record_moderation_action(
+    conn: &mut PgConnection,
+    moderation_action: AddModerationActionEntity,
+) -> QueryResult<usize> {
+    insert_into(moderation_actions::table)
+        .values(moderation_action)
+        .execute(conn)
+}
+
+#[async_trait]
+impl AdminRepository for AdminPostgres {
+    async fn accounts(
+        &self,
+        filter: &AccountsFilter,
+    ) -> DomainResult<Vec<(AccountEntity, Vec<Roles>)>> {
+        let filter = filter.clone();
+        let page_request = filter.page_request();
+
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let accounts = filtered_accounts(&filter)
+                .order_by(accounts::id.asc())
+                .limit(page_request.limit)
+                .offset(page_request.offset())
+                .select(AccountEntity::as_select())
+                .load::<AccountEntity>(conn)?;
+
+            // โหลด role ของทั้งหน้าใน query เดียว
+            let account_ids = accounts
+                .iter()
+                .map(|account| account.id)
+                .collect::<Vec<i32>>();
+
+            let mut roles_by_account = account_roles::table
+                .filter(account_roles::account_id.eq_any(account_ids))
+                .order_by(account_roles::role.asc())
+                .select((account_roles::account_id, account_roles::role))
+                .load::<(i32, Roles)>(conn)?
+                .into_iter()
+                .fold(
+                    HashMap::<i32, Vec<Roles>>::new(),
+                    |mut roles_by_account, (account_id, role)| {
+                        roles_by_account.entry(account_id).or_default().push(role);
+                        roles_by_account
+                    },
+                );
+
+            let result = accounts
+                .into_iter()
+                .map(|account| {
+                    let roles = roles_by_account.remove(&account.id).unwrap_or_default();
+                    (account, roles)
+                })
+                .collect();
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn accounts_counting(&self, filter: &AccountsFilter) -> DomainResult<i64> {
+        let filter = filter.clone();
+
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = filtered_accounts(&filter).count().get_result::<i64>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn suspend_account(
+        &self,
+        moderation_action: AddModerationActionEntity,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let account_id = moderation_action.target_id;
+            let now = moderation_action.created_at;
+
+            let suspended_at = accounts::table
+                .filter(accounts::id.eq(account_id))
+                .select(accounts::suspended_at)
+                .for_update()
+                .first::<Option<chrono::NaiveDateTime>>(conn)?;
+
+            if suspended_at.is_some() {
+                return Err(DomainError::Conflict(
+                    "Account is already suspended".to_string(),
+                ));
+            }
+
+            diesel::update(accounts::table)
+                .filter(accounts::id.eq(account_id))
+                .set((accounts::suspended_at.eq(now), accounts::updated_at.eq(now)))
+                .execute(conn)?;
+
+            // ตัดทุก session ทันที access token ที่ยังไม่หมดอายุก็ใช้ไม่ได้เพราะ middleware เช็ค session
+            diesel::update(refresh_tokens::table)
+                .filter(refresh_tokens::account_id.eq(account_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(now))
+                .execute(conn)?;
+
+            record_moderation_action(conn, moderation_action)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn unsuspend_account(
+        &self,
+        moderation_action: AddModerationActionEntity,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let account_id = moderation_action.target_id;
+
+            let suspended_at = accounts::table
+                .filter(accounts::id.eq(account_id))
+                .select(accounts::suspended_at)
+                .for_update()
+                .first::<Option<chrono::NaiveDateTime>>(conn)?;
+
+            if suspended_at.is_none() {
+                return Err(DomainError::Conflict(
+                    "Account is not suspended".to_string(),
+                ));
+            }
+
+            diesel::update(accounts::table)
+                .filter(accounts::id.eq(account_id))
+                .set((
+                    accounts::suspended_at.eq(None::<chrono::NaiveDateTime>),
+                    accounts::updated_at.eq(moderation_action.created_at),
+                ))
+                .execute(conn)?;
+
+            record_moderation_action(conn, moderation_action)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn restore_quest(
+        &self,
+        moderation_action: AddModerationActionEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest_id = moderation_action.target_id;
+
+            let (deleted_at, guild_commander_id) = quests::table
+                .filter(quests::id.eq(quest_id))
+                .select((quests::deleted_at, quests::guild_commander_id))
+                .for_update()
+                .first::<(Option<chrono::NaiveDateTime>, i32)>(conn)?;
+
+            if deleted_at.is_none() {
+                return Err(DomainError::Conflict("Quest is not deleted".to_string()));
+            }
+
+            let owner_active = diesel::select(exists(
+                guild_commanders::table
+                    .filter(guild_commanders::id.eq(guild_commander_id))
+                    .filter(guild_commanders::deleted_at.is_null()),
+            ))
+            .get_result::<bool>(conn)?;
+
+            // quest ที่เจ้าของปิด account ไปแล้วต้องโอนให้คนอื่นก่อน ไม่งั้นจะไม่มีใครจัดการได้
+            if !owner_active {
+                return Err(DomainError::Conflict(
+                    "Quest owner is no longer active".to_string(),
+                ));
+            }
+
+            diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .set((
+                    quests::deleted_at.eq(None::<chrono::NaiveDateTime>),
+                    quests::updated_at.eq(moderation_action.created_at),
+                ))
+                .execute(conn)?;
+
+            record_moderation_action(conn, moderation_action)?;
+            record_quest_event(conn, quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn override_quest_status(
+        &self,
+        status: QuestStatuses,
+        mut moderation_action: AddModerationActionEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest_id = moderation_action.target_id;
+
+            let current_status = quests::table
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .select(quests::status)
+                .for_update()
+                .first::<QuestStatuses>(conn)?;
+
+            if current_status == status {
+                return Err(DomainError::Conflict(format!(
+                    "Quest is already {}",
+                    status
+                )));
+            }
+
+            diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .set((
+                    quests::status.eq(status),
+                    quests::updated_at.eq(moderation_action.created_at),
+                ))
+                .execute(conn)?;
+
+            moderation_action.details = Some(format!("{} -> {}", current_status, status));
+
+            record_moderation_action(conn, moderation_action)?;
+            record_quest_event(
+                conn,
+                quest_id,
+                quest_event.with_detail("from_status", current_status),
+            )?;
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,19 +1,38 @@
 use std::sync::Arc;
 
-use anyhow::Result;
 use axum::async_trait;
-use diesel::{
-    insert_into,
-    query_dsl::methods::{FilterDsl, SelectDsl},
-    ExpressionMethods, RunQueryDsl, SelectableHelper,
-};
+use diesel::{dsl::delete, prelude::*};
 
 use crate::{
     domain::{
-        entities::adventurers::{AdventurerEntity, RegisterAdventurerEntity},
+        entities::{
+            accounts::RegisterAccountEntity,
+            adventurers::{AdventurerEntity, UpdateAdventurerProfileEntity},
+            guild_commanders::GuildCommanderEntity,
+            quests::QuestEntity,
+        },
+        errors::DomainResult,
         repositories::adventurers::AdventurerRepository,
+        value_objects::{
+            account_credentials::normalize_username, adventurer_model::JoinedQuestsFilter,
+            crew_membership_states::CrewMembershipStates,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_event_model::AddQuestEventModel, quest_statuses::QuestStatuses, roles::Roles,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        repositories::{
+            accounts::{grant_role, insert_account},
+            quest_events::record_quest_event,
+            quest_viewing::crew_count,
+        },
+        schema::{
+            account_roles, accounts, adventurers, guild_commanders, quest_adventurer_junction,
+            quests,
+        },
+        sql_functions::lower,
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::adventurers},
 };
 
 pub struct AdventurerPostgres {
@@ -28,24 +47,155 @@ impl AdventurerPostgres {
 
 #[async_trait]
 impl AdventurerRepository for AdventurerPostgres {
-    async fn register(&self, register_adventurer_entity: RegisterAdventurerEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn register(&self, register_account_entity: RegisterAccountEntity) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let account_id = insert_account(conn, register_account_entity)?;
+
+            let adventurer_id = grant_role(conn, account_id, Roles::Adventurer)?;
+
+            Ok(adventurer_id)
+        })
+        .await
+    }
+    async fn find_by_username(&self, username: String) -> DomainResult<AdventurerEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = adventurers::table
+                .inner_join(accounts::table)
+                .filter(lower(accounts::username).eq(normalize_username(&username)))
+                .filter(adventurers::deleted_at.is_null())
+                .select(AdventurerEntity::as_select())
+                .first::<AdventurerEntity>(conn)?;
 
-        let result = insert_into(adventurers::table)
-            .values(register_adventurer_entity)
-            .returning(adventurers::id)
-            .get_result(&mut conn)?;
+            Ok(result)
+        })
+        .await
+    }
+    async fn find_by_id(&self, adventurer_id: i32) -> DomainResult<AdventurerEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = adventurers::table
+                .inner_join(accounts::table)
+                .filter(adventurers::id.eq(adventurer_id))
+                .filter(adventurers::deleted_at.is_null())
+                .select(AdventurerEntity::as_select())
+                .first::<AdventurerEntity>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
-    async fn find_by_username(&self, username: String) -> Result<AdventurerEntity> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn update_profile(
+        &self,
+        adventurer_id: i32,
+        update_adventurer_profile_entity: UpdateAdventurerProfileEntity,
+    ) -> DomainResult<AdventurerEntity> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            diesel::update(adventurers::table)
+                .filter(adventurers::id.eq(adventurer_id))
+                .filter(adventurers::deleted_at.is_null())
+                .set(update_adventurer_profile_entity)
+                .returning(adventurers::id)
+                .get_result::<i32>(conn)?;
+
+            // RETURNING ดึง username จาก accounts ไม่ได้ เลยอ่านกลับพร้อม join อีกรอบ
+            let result = adventurers::table
+                .inner_join(accounts::table)
+                .filter(adventurers::id.eq(adventurer_id))
+                .select(AdventurerEntity::as_select())
+                .first::<AdventurerEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn deactivate(
+        &self,
+        adventurer_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let now = chrono::Utc::now().naive_utc();
+
+            let account_id = diesel::update(adventurers::table)
+                .filter(adventurers::id.eq(adventurer_id))
+                .filter(adventurers::deleted_at.is_null())
+                .set((
+                    adventurers::deleted_at.eq(now),
+                    adventurers::updated_at.eq(now),
+                ))
+                .returning(adventurers::account_id)
+                .get_result::<i32>(conn)?;
+
+            // account ยังอยู่และใช้ role อื่นต่อได้ แค่ถอด role นี้ออก
+            delete(account_roles::table)
+                .filter(account_roles::account_id.eq(account_id))
+                .filter(account_roles::role.eq(Roles::Adventurer))
+                .execute(conn)?;
+
+            // lock quest ที่ยัง Open ก่อนถอนตัว เหมือน leave ใน crew_switchboard
+            let open_quest_ids = quests::table
+                .filter(
+                    quests::id.eq_any(
+                        quest_adventurer_junction::table
+                            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+                            .select(quest_adventurer_junction::quest_id),
+                    ),
+                )
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open))
+                .select(quests::id)
+                .for_update()
+                .load::<i32>(conn)?;
+
+            let left_quest_ids = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+                .filter(quest_adventurer_junction::quest_id.eq_any(open_quest_ids))
+                .returning(quest_adventurer_junction::quest_id)
+                .get_results::<i32>(conn)?;
+
+            for quest_id in left_quest_ids {
+                record_quest_event(conn, quest_id, quest_event.clone())?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
+    async fn joined_quests(
+        &self,
+        adventurer: &AdventurerEntity,
+        filter: &JoinedQuestsFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64, GuildCommanderEntity)>> {
+        let adventurer = adventurer.clone();
+        let filter = filter.clone();
+
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            // คำขอที่ยัง Pending หรือคำชวนที่ยังไม่ตอบ ยังไม่นับว่าอยู่ใน crew
+            let joined_quest_ids = QuestAdventurerJunction::belonging_to(&adventurer)
+                .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+                .select(quest_adventurer_junction::quest_id);
+
+            let mut query = quests::table
+                .inner_join(guild_commanders::table.inner_join(accounts::table))
+                .filter(quests::id.eq_any(joined_quest_ids))
+                .filter(quests::deleted_at.is_null())
+                .into_boxed();
+
+            if let Some(status) = filter.status {
+                query = query.filter(quests::status.eq(status));
+            }
 
-        let result = adventurers::table
-            .filter(adventurers::username.eq(username))
-            .select(AdventurerEntity::as_select())
-            .first::<AdventurerEntity>(&mut conn)?;
+            let result = query
+                .order_by(quests::created_at.desc())
+                .then_order_by(quests::id.asc())
+                .select((
+                    QuestEntity::as_select(),
+                    crew_count(),
+                    GuildCommanderEntity::as_select(),
+                ))
+                .load::<(QuestEntity, i64, GuildCommanderEntity)>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 }
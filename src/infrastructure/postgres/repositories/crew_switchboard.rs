@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use anyhow::Result;
 use axum::async_trait;
 use diesel::{
     dsl::{delete, insert_into},
@@ -9,11 +8,18 @@ use diesel::{
 
 use crate::{
     domain::{
+        errors::{DomainError, DomainResult},
         repositories::crew_switchboard::CrewSwitchboardRepository,
-        value_objects::quest_adventurer_junction::QuestAdventurerJunction,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_event_model::AddQuestEventModel,
+        },
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, schema::quest_adventurer_junction,
+        postgres_connection::{unit_of_work, PgPoolSquad},
+        repositories::{quest_events::record_quest_event, quest_ops::lock_quest},
+        schema::quest_adventurer_junction,
     },
 };
 
@@ -27,25 +33,254 @@ impl CrewSwitchboardPostgres {
     }
 }
 
+pub(crate) fn joined_counting(conn: &mut PgConnection, quest_id: i32) -> QueryResult<i64> {
+    quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+        .count()
+        .get_result::<i64>(conn)
+}
+
+fn find_membership(
+    conn: &mut PgConnection,
+    junction_body: &QuestAdventurerJunction,
+) -> QueryResult<Option<CrewMembershipStates>> {
+    quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+        .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+        .select(quest_adventurer_junction::state)
+        .first::<CrewMembershipStates>(conn)
+        .optional()
+}
+
+fn update_membership(
+    conn: &mut PgConnection,
+    junction_body: &QuestAdventurerJunction,
+    state: CrewMembershipStates,
+) -> QueryResult<usize> {
+    diesel::update(quest_adventurer_junction::table)
+        .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+        .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+        .set((
+            quest_adventurer_junction::state.eq(state),
+            quest_adventurer_junction::joined_at.eq(chrono::Utc::now().naive_utc()),
+        ))
+        .execute(conn)
+}
+
 #[async_trait]
 impl CrewSwitchboardRepository for CrewSwitchboardPostgres {
-    async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<CrewMembershipStates> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            let membership = find_membership(conn, &junction_body)?;
+
+            match membership {
+                Some(CrewMembershipStates::Joined) => {
+                    return Err(DomainError::Conflict(
+                        "Adventurer already joined this quest".to_string(),
+                    ));
+                }
+                Some(CrewMembershipStates::Pending) => {
+                    return Err(DomainError::Conflict(
+                        "Join request is already pending".to_string(),
+                    ));
+                }
+                _ => {}
+            }
+
+            let adventurer_count = joined_counting(conn, junction_body.quest_id)?;
+
+            quest.ensure_joinable(adventurer_count)?;
+
+            // ถูกชวนไว้แล้วเข้าได้เลยไม่ต้องรออนุมัติ
+            if membership == Some(CrewMembershipStates::Invited) {
+                update_membership(conn, &junction_body, CrewMembershipStates::Joined)?;
+                record_quest_event(
+                    conn,
+                    junction_body.quest_id,
+                    quest_event.with_detail("state", CrewMembershipStates::Joined),
+                )?;
+
+                return Ok(CrewMembershipStates::Joined);
+            }
+
+            let state = if quest.approval_required {
+                CrewMembershipStates::Pending
+            } else {
+                CrewMembershipStates::Joined
+            };
+
+            let quest_id = junction_body.quest_id;
+
+            insert_into(quest_adventurer_junction::table)
+                .values((junction_body, quest_adventurer_junction::state.eq(state)))
+                .execute(conn)?;
+            record_quest_event(conn, quest_id, quest_event.with_detail("state", state))?;
+
+            Ok(state)
+        })
+        .await
+    }
+    async fn leave(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            // ถอนคำขอหรือปฏิเสธคำชวนได้ตลอด ส่วนคนที่ join แล้วต้องดูสถานะ quest
+            if find_membership(conn, &junction_body)? == Some(CrewMembershipStates::Joined) {
+                quest.ensure_leaveable()?;
+            }
+
+            let deleted = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .execute(conn)?;
+
+            if deleted == 0 {
+                return Err(DomainError::NotFound(
+                    "Adventurer has no membership in this quest".to_string(),
+                ));
+            }
+
+            record_quest_event(conn, junction_body.quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn kick(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+            quest.ensure_crew_editable()?;
+
+            let deleted = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .execute(conn)?;
+
+            if deleted == 0 {
+                return Err(DomainError::NotFound(
+                    "Adventurer is not in this quest's crew".to_string(),
+                ));
+            }
+
+            record_quest_event(conn, junction_body.quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn invite(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+
+            match find_membership(conn, &junction_body)? {
+                Some(CrewMembershipStates::Pending) => {
+                    return Err(DomainError::Conflict(
+                        "Adventurer already requested to join, approve the request instead"
+                            .to_string(),
+                    ));
+                }
+                Some(_) => {
+                    return Err(DomainError::Conflict(
+                        "Adventurer is already in this quest's crew".to_string(),
+                    ));
+                }
+                None => {}
+            }
+
+            let adventurer_count = joined_counting(conn, junction_body.quest_id)?;
+
+            quest.ensure_joinable(adventurer_count)?;
 
-        insert_into(quest_adventurer_junction::table)
-            .values(junction_body)
-            .execute(&mut conn)?;
+            let quest_id = junction_body.quest_id;
 
-        Ok(())
+            insert_into(quest_adventurer_junction::table)
+                .values((
+                    junction_body,
+                    quest_adventurer_junction::state.eq(CrewMembershipStates::Invited),
+                ))
+                .execute(conn)?;
+            record_quest_event(conn, quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
     }
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn approve(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+
+            if find_membership(conn, &junction_body)? != Some(CrewMembershipStates::Pending) {
+                return Err(DomainError::NotFound("Join request not found".to_string()));
+            }
+
+            let adventurer_count = joined_counting(conn, junction_body.quest_id)?;
+
+            quest.ensure_joinable(adventurer_count)?;
+
+            update_membership(conn, &junction_body, CrewMembershipStates::Joined)?;
+            record_quest_event(conn, junction_body.quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn reject(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, junction_body.quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+
+            let deleted = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Pending))
+                .execute(conn)?;
+
+            if deleted == 0 {
+                return Err(DomainError::NotFound("Join request not found".to_string()));
+            }
 
-        delete(quest_adventurer_junction::table)
-            .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
-            .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
-            .execute(&mut conn)?;
+            record_quest_event(conn, junction_body.quest_id, quest_event)?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
@@ -1,21 +1,33 @@
 use std::sync::Arc;
 
-use anyhow::Result;
 use axum::async_trait;
 use diesel::{
-    dsl::insert_into,
-    query_dsl::methods::{FilterDsl, SelectDsl},
-    ExpressionMethods, RunQueryDsl, SelectableHelper,
+    dsl::{count_star, delete},
+    prelude::*,
 };
 
 use crate::{
     domain::{
-        entities::guild_commanders::{GuildCommanderEntity, RegisterGuildCommanderEntity},
+        entities::{
+            accounts::RegisterAccountEntity,
+            guild_commanders::{GuildCommanderEntity, UpdateGuildCommanderProfileEntity},
+            quests::QuestEntity,
+        },
+        errors::{DomainError, DomainResult},
         repositories::guild_commanders::GuildCommanderRepository,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates, guild_commander_model::OwnedQuestsFilter,
+            quest_event_model::AddQuestEventModel, quest_statuses::QuestStatuses, roles::Roles,
+        },
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad,
-        schema::{guild_commanders, quests::guild_commander_id},
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        repositories::{
+            accounts::{grant_role, insert_account},
+            quest_events::record_quest_event,
+            quest_viewing::crew_count,
+        },
+        schema::{account_roles, accounts, guild_commanders, quest_adventurer_junction, quests},
     },
 };
 
@@ -31,27 +43,189 @@ impl GuildCommanderPostgres {
 
 #[async_trait]
 impl GuildCommanderRepository for GuildCommanderPostgres {
-    async fn register(
+    async fn register(&self, register_account_entity: RegisterAccountEntity) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let account_id = insert_account(conn, register_account_entity)?;
+
+            let guild_commander_id = grant_role(conn, account_id, Roles::GuildCommander)?;
+
+            Ok(guild_commander_id)
+        })
+        .await
+    }
+    async fn find_by_id(&self, guild_commander_id: i32) -> DomainResult<GuildCommanderEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = guild_commanders::table
+                .inner_join(accounts::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .filter(guild_commanders::deleted_at.is_null())
+                .select(GuildCommanderEntity::as_select())
+                .first::<GuildCommanderEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn update_profile(
         &self,
-        register_guild_commander_entity: RegisterGuildCommanderEntity,
-    ) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+        guild_commander_id: i32,
+        update_guild_commander_profile_entity: UpdateGuildCommanderProfileEntity,
+    ) -> DomainResult<GuildCommanderEntity> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            diesel::update(guild_commanders::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .filter(guild_commanders::deleted_at.is_null())
+                .set(update_guild_commander_profile_entity)
+                .returning(guild_commanders::id)
+                .get_result::<i32>(conn)?;
 
-        let result = insert_into(guild_commanders::table)
-            .values(register_guild_commander_entity)
-            .returning(guild_commanders::id)
-            .get_result(&mut conn)?;
+            // RETURNING ดึง username จาก accounts ไม่ได้ เลยอ่านกลับพร้อม join อีกรอบ
+            let result = guild_commanders::table
+                .inner_join(accounts::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .select(GuildCommanderEntity::as_select())
+                .first::<GuildCommanderEntity>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
-    async fn find_by_username(&self, username: String) -> Result<GuildCommanderEntity> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn deactivate(
+        &self,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let now = chrono::Utc::now().naive_utc();
+
+            let account_id = diesel::update(guild_commanders::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .filter(guild_commanders::deleted_at.is_null())
+                .set((
+                    guild_commanders::deleted_at.eq(now),
+                    guild_commanders::updated_at.eq(now),
+                ))
+                .returning(guild_commanders::account_id)
+                .get_result::<i32>(conn)?;
+
+            // account ยังอยู่และใช้ role อื่นต่อได้ แค่ถอด role นี้ออก
+            delete(account_roles::table)
+                .filter(account_roles::account_id.eq(account_id))
+                .filter(account_roles::role.eq(Roles::GuildCommander))
+                .execute(conn)?;
+
+            let active_quests = quests::table
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq_any([
+                    QuestStatuses::Open,
+                    QuestStatuses::InJourney,
+                    QuestStatuses::Failed,
+                ]))
+                .select((quests::id, quests::status))
+                .for_update()
+                .load::<(i32, QuestStatuses)>(conn)?;
+
+            // quest ที่กำลังออกเดินทาง หรือล้มเหลวแล้วยังออกเดินทางใหม่ได้ ต้องจบหรือโอนให้คนอื่นก่อน
+            if active_quests
+                .iter()
+                .any(|(_, status)| *status == QuestStatuses::InJourney)
+            {
+                return Err(DomainError::Conflict(
+                    "Guild commander still has quests in journey".to_string(),
+                ));
+            }
+
+            if active_quests
+                .iter()
+                .any(|(_, status)| *status == QuestStatuses::Failed)
+            {
+                return Err(DomainError::Conflict(
+                    "Guild commander still has failed quests".to_string(),
+                ));
+            }
+
+            let open_quest_ids = active_quests
+                .into_iter()
+                .map(|(quest_id, _)| quest_id)
+                .collect::<Vec<i32>>();
+
+            // quest ที่มี crew อยู่แล้วต้องโอนให้คนอื่นก่อน ลบทิ้งไม่ได้
+            let crewed_quests = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq_any(&open_quest_ids))
+                .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+                .count()
+                .get_result::<i64>(conn)?;
 
-        let result = guild_commanders::table
-            .filter(guild_commanders::username.eq(username))
-            .select(GuildCommanderEntity::as_select())
-            .first::<GuildCommanderEntity>(&mut conn)?;
+            if crewed_quests > 0 {
+                return Err(DomainError::Conflict(
+                    "Guild commander still has quests with crew members".to_string(),
+                ));
+            }
+
+            // คำขอเข้าร่วมกับคำชวนที่ค้างอยู่ไม่มีความหมายแล้วเมื่อ quest ถูกลบ
+            delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq_any(&open_quest_ids))
+                .execute(conn)?;
+
+            diesel::update(quests::table)
+                .filter(quests::id.eq_any(&open_quest_ids))
+                .set((quests::deleted_at.eq(now), quests::updated_at.eq(now)))
+                .execute(conn)?;
+
+            for quest_id in open_quest_ids {
+                record_quest_event(conn, quest_id, quest_event.clone())?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
+    async fn owned_quests(
+        &self,
+        guild_commander_id: i32,
+        filter: &OwnedQuestsFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64)>> {
+        let filter = filter.clone();
+
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let mut query = quests::table
+                .inner_join(guild_commanders::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .filter(guild_commanders::deleted_at.is_null())
+                .filter(quests::deleted_at.is_null())
+                .into_boxed();
+
+            if let Some(status) = filter.status {
+                query = query.filter(quests::status.eq(status));
+            }
+
+            let result = query
+                .order_by(quests::created_at.desc())
+                .then_order_by(quests::id.asc())
+                .select((QuestEntity::as_select(), crew_count()))
+                .load::<(QuestEntity, i64)>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn owned_quest_status_counting(
+        &self,
+        guild_commander_id: i32,
+    ) -> DomainResult<Vec<(QuestStatuses, i64)>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quests::table
+                .inner_join(guild_commanders::table)
+                .filter(guild_commanders::id.eq(guild_commander_id))
+                .filter(guild_commanders::deleted_at.is_null())
+                .filter(quests::deleted_at.is_null())
+                .group_by(quests::status)
+                .select((quests::status, count_star()))
+                .load::<(QuestStatuses, i64)>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 }
@@ -1,15 +1,22 @@
 use std::sync::Arc;
 
-use anyhow::Result;
 use axum::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl};
+use diesel::prelude::*;
 
 use crate::{
     domain::{
+        errors::DomainResult,
         repositories::journey_ledger::JourneyLedgerRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{quest_event_model::AddQuestEventModel, quest_statuses::QuestStatuses},
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, PgPoolSquad},
+        repositories::{
+            crew_switchboard::joined_counting, quest_events::record_quest_event,
+            quest_ops::lock_quest,
+        },
+        schema::quests,
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct JourneyLedgerPostgres {
@@ -22,51 +29,100 @@ impl JourneyLedgerPostgres {
     }
 }
 
-#[async_trait]
-impl JourneyLedgerRepository for JourneyLedgerPostgres {
-    async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+// เช็ค transition ซ้ำกับแถวที่ lock แล้ว กันสองคำขอผ่านพร้อมกัน (เช่น Completed กับ Failed)
+// และกัน join ที่แทรกเข้ามาระหว่างเช็คจำนวน crew กับการออกเดินทาง
+fn transition(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    guild_commander_id: i32,
+    next: QuestStatuses,
+    quest_event: AddQuestEventModel,
+) -> DomainResult<i32> {
+    let quest = lock_quest(conn, quest_id)?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::InJourney.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+    quest.ensure_owned_by(guild_commander_id)?;
 
-        Ok(result)
-    }
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    // จำนวน crew มีผลแค่ตอนออกเดินทาง
+    let crew_size = if next == QuestStatuses::InJourney {
+        joined_counting(conn, quest_id)?
+    } else {
+        0
+    };
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Completed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+    quest
+        .status
+        .transition_to(next, crew_size, quest.crew_limits())?;
 
-        Ok(result)
-    }
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    let result = diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .filter(quests::deleted_at.is_null())
+        .filter(quests::guild_commander_id.eq(guild_commander_id))
+        .filter(quests::status.eq(quest.status))
+        .set(quests::status.eq(next))
+        .returning(quests::id)
+        .get_result::<i32>(conn)?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .set((
-                quests::status.eq(QuestStatuses::Failed.to_string()),
-                quests::guild_commander_id.eq(guild_commander_id),
-            ))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+    // สถานะเดิมอ่านจากแถวที่ lock ไว้ ไม่ใช่ค่าที่ usecase อ่านไว้ก่อนหน้า
+    record_quest_event(
+        conn,
+        result,
+        quest_event.with_detail("from_status", quest.status),
+    )?;
 
-        Ok(result)
+    Ok(result)
+}
+
+#[async_trait]
+impl JourneyLedgerRepository for JourneyLedgerPostgres {
+    async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            transition(
+                conn,
+                quest_id,
+                guild_commander_id,
+                QuestStatuses::InJourney,
+                quest_event,
+            )
+        })
+        .await
+    }
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            transition(
+                conn,
+                quest_id,
+                guild_commander_id,
+                QuestStatuses::Completed,
+                quest_event,
+            )
+        })
+        .await
+    }
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            transition(
+                conn,
+                quest_id,
+                guild_commander_id,
+                QuestStatuses::Failed,
+                quest_event,
+            )
+        })
+        .await
     }
 }
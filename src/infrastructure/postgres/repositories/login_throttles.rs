@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::{delete, insert_into, max},
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::login_throttles::LoginThrottleEntity, errors::DomainResult,
+        repositories::login_throttles::LoginThrottleRepository,
+        value_objects::login_throttle_policy::LoginThrottlePolicy,
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        schema::login_throttles,
+    },
+};
+
+pub struct LoginThrottlePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LoginThrottlePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for LoginThrottlePostgres {
+    async fn locked_until(
+        &self,
+        throttle_keys: Vec<String>,
+    ) -> DomainResult<Option<NaiveDateTime>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = login_throttles::table
+                .filter(login_throttles::throttle_key.eq_any(throttle_keys))
+                .filter(login_throttles::locked_until.gt(chrono::Utc::now().naive_utc()))
+                .select(max(login_throttles::locked_until))
+                .first::<Option<NaiveDateTime>>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn record_failure(
+        &self,
+        throttle_key: String,
+        policy: LoginThrottlePolicy,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let now = chrono::Utc::now().naive_utc();
+
+            insert_into(login_throttles::table)
+                .values((
+                    login_throttles::throttle_key.eq(&throttle_key),
+                    login_throttles::last_failed_at.eq(now),
+                ))
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            // lock แถวไว้ให้ request ที่ผิดพร้อมกันนับต่อกันได้ถูกต้อง
+            let current = login_throttles::table
+                .filter(login_throttles::throttle_key.eq(&throttle_key))
+                .select(LoginThrottleEntity::as_select())
+                .for_update()
+                .first::<LoginThrottleEntity>(conn)?;
+
+            let failed_attempts = current.next_failed_attempts(&policy, now);
+            let locked_until = policy.lockout(failed_attempts).map(|lockout| now + lockout);
+
+            diesel::update(login_throttles::table)
+                .filter(login_throttles::throttle_key.eq(&throttle_key))
+                .set((
+                    login_throttles::failed_attempts.eq(failed_attempts),
+                    login_throttles::locked_until.eq(locked_until),
+                    login_throttles::last_failed_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn reset(&self, throttle_key: String) -> DomainResult<()> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            delete(login_throttles::table)
+                .filter(login_throttles::throttle_key.eq(throttle_key))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,6 +1,11 @@
+pub mod accounts;
+pub mod admin;
 pub mod adventurers;
 pub mod crew_switchboard;
 pub mod guild_commanders;
 pub mod journey_ledger;
+pub mod login_throttles;
+pub mod quest_events;
 pub mod quest_ops;
 pub mod quest_viewing;
+pub mod refresh_tokens;
@@ -0,0 +1,17 @@
+use diesel::{dsl::insert_into, prelude::*};
+
+use crate::{
+    domain::value_objects::quest_event_model::AddQuestEventModel,
+    infrastructure::postgres::schema::quest_events,
+};
+
+// เรียกจากใน unit_of_work ของ repository ที่เปลี่ยน quest เท่านั้น
+pub(crate) fn record_quest_event(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    quest_event: AddQuestEventModel,
+) -> QueryResult<usize> {
+    insert_into(quest_events::table)
+        .values(quest_event.to_entity(quest_id))
+        .execute(conn)
+}
@@ -1,17 +1,22 @@
 use std::sync::Arc;
 
-use anyhow::Result;
 use axum::async_trait;
-use diesel::insert_into;
+use diesel::dsl::{exists, insert_into, select};
 use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 
 use crate::{
     domain::{
-        entities::quests::{AddQuestEntity, EditQuestEntity},
+        entities::quests::{AddQuestEntity, EditQuestEntity, QuestEntity},
+        errors::{DomainError, DomainResult},
         repositories::quest_ops::QuestOpsRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{quest_event_model::AddQuestEventModel, quest_statuses::QuestStatuses},
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, PgPoolSquad},
+        repositories::{crew_switchboard::joined_counting, quest_events::record_quest_event},
+        schema::{guild_commanders, quests},
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct QuestOpsPostgres {
@@ -24,43 +29,149 @@ impl QuestOpsPostgres {
     }
 }
 
+// lock แถวของ quest ไว้จนจบ transaction กันไม่ให้ join/leave หรือเปลี่ยนสถานะพร้อมกัน
+// การเช็คเจ้าของและสถานะต้องทำกับแถวที่ lock แล้ว ไม่ใช่ค่าที่อ่านมาก่อนหน้าใน usecase
+pub(crate) fn lock_quest(conn: &mut PgConnection, quest_id: i32) -> QueryResult<QuestEntity> {
+    quests::table
+        .filter(quests::id.eq(quest_id))
+        .filter(quests::deleted_at.is_null())
+        .select(QuestEntity::as_select())
+        .for_update()
+        .first::<QuestEntity>(conn)
+}
+
 #[async_trait]
 impl QuestOpsRepository for QuestOpsPostgres {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = insert_into(quests::table)
-            .values(add_quest_entity)
-            .returning(quests::id)
-            .get_result(&mut conn)?;
-
-        Ok(result)
+    async fn add(
+        &self,
+        add_quest_entity: AddQuestEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let result = insert_into(quests::table)
+                .values(add_quest_entity)
+                .returning(quests::id)
+                .get_result(conn)?;
+            record_quest_event(conn, result, quest_event)?;
+
+            Ok(result)
+        })
+        .await
     }
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set(edit_quest_entity)
-            .returning(quests::id)
-            .get_result(&mut conn)?;
-
-        Ok(result)
+    async fn edit(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        edit_quest_entity: EditQuestEntity,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+            quest.ensure_modifiable()?;
+
+            if joined_counting(conn, quest_id)? > 0 {
+                return Err(DomainError::Conflict(
+                    "Quest is already started".to_string(),
+                ));
+            }
+
+            edit_quest_entity.crew_limits(quest.crew_limits())?;
+
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .set(edit_quest_entity)
+                .returning(quests::id)
+                .get_result(conn)?;
+            record_quest_event(conn, result, quest_event)?;
+
+            Ok(result)
+        })
+        .await
     }
-    async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set((
-                quests::deleted_at.eq(chrono::Utc::now().naive_utc()),
-                quests::guild_commander_id.eq(guild_commander_id),
+    async fn remove(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let quest = lock_quest(conn, quest_id)?;
+
+            quest.ensure_owned_by(guild_commander_id)?;
+            quest.ensure_modifiable()?;
+
+            // นับใต้ lock เดียวกับ join ไม่งั้น join ที่เข้ามาพร้อมกันจะค้างอยู่ใน quest ที่ถูกลบ
+            if joined_counting(conn, quest_id)? > 0 {
+                return Err(DomainError::Conflict(
+                    "Quest is already started".to_string(),
+                ));
+            }
+
+            let removed = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open))
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .set(quests::deleted_at.eq(chrono::Utc::now().naive_utc()))
+                .execute(conn)?;
+
+            if removed == 0 {
+                return Err(DomainError::NotFound("Quest not found".to_string()));
+            }
+
+            record_quest_event(conn, quest_id, quest_event)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn transfer_ownership(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        to_guild_commander_id: i32,
+        quest_event: AddQuestEventModel,
+    ) -> DomainResult<i32> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            lock_quest(conn, quest_id)?.ensure_owned_by(guild_commander_id)?;
+
+            let is_active_guild_commander = select(exists(
+                guild_commanders::table
+                    .filter(guild_commanders::id.eq(to_guild_commander_id))
+                    .filter(guild_commanders::deleted_at.is_null()),
             ))
-            .execute(&mut conn)?;
+            .get_result::<bool>(conn)?;
+
+            if !is_active_guild_commander {
+                return Err(DomainError::NotFound(
+                    "Guild commander not found".to_string(),
+                ));
+            }
+
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .set((
+                    quests::guild_commander_id.eq(to_guild_commander_id),
+                    quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .returning(quests::id)
+                .get_result(conn)
+                .map_err(|e| match e {
+                    DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                        DomainError::NotFound("Guild commander not found".to_string())
+                    }
+                    e => e.into(),
+                })?;
+            record_quest_event(conn, result, quest_event)?;
 
-        Ok(())
+            Ok(result)
+        })
+        .await
     }
 }
@@ -2,17 +2,27 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::quests::QuestEntity, repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        entities::{quest_events::QuestEventEntity, quests::QuestEntity},
+        errors::DomainResult,
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::{BoardCheckingFilter, BoardSortField, SortDirection},
+            crew_membership_states::CrewMembershipStates,
+            quest_adventurer_junction::CrewMemberModel,
+            quest_event_model::QuestHistoryFilter,
+        },
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad,
-        schema::{quest_adventurer_junction, quests},
+        postgres_connection::{with_connection, PgPoolSquad},
+        schema::{accounts, adventurers, quest_adventurer_junction, quest_events, quests},
     },
 };
-use anyhow::Result;
 use axum::async_trait;
-use diesel::prelude::*;
+use diesel::{
+    dsl::{AssumeNotNull, Count, Eq, Filter, SingleValue},
+    pg::Pg,
+    prelude::*,
+};
 
 pub struct QuestViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -24,49 +34,194 @@ impl QuestViewingPostgres {
     }
 }
 
+pub(crate) type CrewCount = AssumeNotNull<
+    SingleValue<
+        Count<
+            Filter<
+                Filter<
+                    quest_adventurer_junction::table,
+                    Eq<quest_adventurer_junction::quest_id, quests::id>,
+                >,
+                Eq<quest_adventurer_junction::state, CrewMembershipStates>,
+            >,
+        >,
+    >,
+>;
+
+// นับ crew เป็น subquery ใน select เดียวกับ quest แทนการ query แยกทีละ quest
+pub(crate) fn crew_count() -> CrewCount {
+    quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quests::id))
+        .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+        .count()
+        .single_value()
+        .assume_not_null()
+}
+
+fn board_filtered_quests(filter: &BoardCheckingFilter) -> quests::BoxedQuery<'_, Pg> {
+    let mut query = quests::table
+        .filter(quests::deleted_at.is_null())
+        .into_boxed();
+
+    if let Some(name) = &filter.name {
+        query = query.filter(quests::name.ilike(format!("%{}%", name)));
+    }
+
+    if let Some(status) = &filter.status {
+        query = query.filter(quests::status.eq(*status));
+    }
+
+    query
+}
+
 #[async_trait]
 impl QuestViewingRepository for QuestViewingPostgres {
-    async fn view_details(&self, quest_id: i32) -> Result<QuestEntity> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn view_details_with_crew_count(
+        &self,
+        quest_id: i32,
+    ) -> DomainResult<(QuestEntity, i64)> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quests::table
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .select((QuestEntity::as_select(), crew_count()))
+                .first::<(QuestEntity, i64)>(conn)?;
 
-        let result = quests::table
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .select(QuestEntity::as_select())
-            .first::<QuestEntity>(&mut conn)?;
+            Ok(result)
+        })
+        .await
+    }
+    async fn view_details(&self, quest_id: i32) -> DomainResult<QuestEntity> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quests::table
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .select(QuestEntity::as_select())
+                .first::<QuestEntity>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+    ) -> DomainResult<Vec<(QuestEntity, i64)>> {
+        let filter = filter.clone();
+        let page_request = filter.page_request();
+
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let query = board_filtered_quests(&filter);
+
+            let query = match (filter.sort_by(), filter.sort_direction()) {
+                (BoardSortField::Name, SortDirection::Asc) => query.order_by(quests::name.asc()),
+                (BoardSortField::Name, SortDirection::Desc) => query.order_by(quests::name.desc()),
+                (BoardSortField::CreatedAt, SortDirection::Asc) => {
+                    query.order_by(quests::created_at.asc())
+                }
+                (BoardSortField::CreatedAt, SortDirection::Desc) => {
+                    query.order_by(quests::created_at.desc())
+                }
+                (BoardSortField::UpdatedAt, SortDirection::Asc) => {
+                    query.order_by(quests::updated_at.asc())
+                }
+                (BoardSortField::UpdatedAt, SortDirection::Desc) => {
+                    query.order_by(quests::updated_at.desc())
+                }
+                (BoardSortField::CrewCount, SortDirection::Asc) => {
+                    query.order_by(crew_count().asc())
+                }
+                (BoardSortField::CrewCount, SortDirection::Desc) => {
+                    query.order_by(crew_count().desc())
+                }
+            };
+
+            // ใส่ id ต่อท้ายให้ลำดับคงที่เวลาค่าที่ sort ซ้ำกัน
+            let result = query
+                .then_order_by(quests::id.asc())
+                .limit(page_request.limit)
+                .offset(page_request.offset())
+                .select((QuestEntity::as_select(), crew_count()))
+                .load::<(QuestEntity, i64)>(conn)?;
 
-        let mut query = quests::table
-            .filter(quests::deleted_at.is_null())
-            .into_boxed();
+            Ok(result)
+        })
+        .await
+    }
+    async fn board_counting(&self, filter: &BoardCheckingFilter) -> DomainResult<i64> {
+        let filter = filter.clone();
 
-        if let Some(name) = &filter.name {
-            query = query.filter(quests::name.ilike(format!("%{}%", name)));
-        }
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = board_filtered_quests(&filter)
+                .count()
+                .get_result::<i64>(conn)?;
 
-        if let Some(status) = &filter.status {
-            query = query.filter(quests::status.eq(status.to_string()));
-        }
+            Ok(result)
+        })
+        .await
+    }
+    async fn adventurer_counting_by_quest_id(&self, quest_id: i32) -> DomainResult<i64> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+                .count()
+                .first::<i64>(conn)?;
 
-        let result = query
-            .select(QuestEntity::as_select())
-            .order_by(quests::created_at.desc())
-            .load::<QuestEntity>(&mut conn)?;
+            Ok(result)
+        })
+        .await
+    }
+    async fn crew_roster(&self, quest_id: i32) -> DomainResult<Vec<CrewMemberModel>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quest_adventurer_junction::table
+                .inner_join(adventurers::table.inner_join(accounts::table))
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                // คำขอที่รออนุมัติหรือคำเชิญที่ยังไม่ตอบรับไม่ใช่ crew และไม่ควรเปิดให้คนนอกเห็น
+                .filter(quest_adventurer_junction::state.eq(CrewMembershipStates::Joined))
+                .order_by(quest_adventurer_junction::joined_at.asc())
+                .then_order_by(adventurers::id.asc())
+                .select((
+                    adventurers::id,
+                    accounts::username,
+                    quest_adventurer_junction::joined_at,
+                    quest_adventurer_junction::state,
+                ))
+                .load::<CrewMemberModel>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
-    async fn adventurer_counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn history(
+        &self,
+        quest_id: i32,
+        filter: &QuestHistoryFilter,
+    ) -> DomainResult<Vec<QuestEventEntity>> {
+        let page_request = filter.page_request();
 
-        let result = quest_adventurer_junction::table
-            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
-            .count()
-            .first::<i64>(&mut conn)?;
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quest_events::table
+                .filter(quest_events::quest_id.eq(quest_id))
+                .order((quest_events::created_at.asc(), quest_events::id.asc()))
+                .limit(page_request.limit)
+                .offset(page_request.offset())
+                .select(QuestEventEntity::as_select())
+                .load::<QuestEventEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn history_counting(&self, quest_id: i32) -> DomainResult<i64> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = quest_events::table
+                .filter(quest_events::quest_id.eq(quest_id))
+                .count()
+                .get_result::<i64>(conn)?;
 
-        Ok(result)
+            Ok(result)
+        })
+        .await
     }
 }
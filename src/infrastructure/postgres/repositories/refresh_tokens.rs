@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::{exists, insert_into, select, Filter, Gt, IsNull},
+    prelude::*,
+};
+
+use crate::{
+    domain::{
+        entities::refresh_tokens::{
+            AddRefreshTokenEntity, RefreshTokenEntity, RefreshTokenRotation,
+        },
+        errors::{DomainError, DomainResult},
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::{unit_of_work, with_connection, PgPoolSquad},
+        schema::refresh_tokens,
+    },
+};
+
+pub struct RefreshTokenPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RefreshTokenPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+// refresh token ตัวล่าสุดของแต่ละ family ที่ยังใช้ต่อได้
+type ActiveHeads = Filter<
+    Filter<
+        Filter<refresh_tokens::table, IsNull<refresh_tokens::used_at>>,
+        IsNull<refresh_tokens::revoked_at>,
+    >,
+    Gt<refresh_tokens::expires_at, NaiveDateTime>,
+>;
+
+fn active_heads() -> ActiveHeads {
+    refresh_tokens::table
+        .filter(refresh_tokens::used_at.is_null())
+        .filter(refresh_tokens::revoked_at.is_null())
+        .filter(refresh_tokens::expires_at.gt(chrono::Utc::now().naive_utc()))
+}
+
+fn revoke_family_by_id(conn: &mut PgConnection, family_id: &str) -> QueryResult<usize> {
+    diesel::update(refresh_tokens::table)
+        .filter(refresh_tokens::family_id.eq(family_id))
+        .filter(refresh_tokens::revoked_at.is_null())
+        .set(refresh_tokens::revoked_at.eq(chrono::Utc::now().naive_utc()))
+        .execute(conn)
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenPostgres {
+    async fn issue(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> DomainResult<i32> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = insert_into(refresh_tokens::table)
+                .values(add_refresh_token_entity)
+                .returning(refresh_tokens::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn rotate(
+        &self,
+        token_hash: String,
+        next_token_hash: String,
+    ) -> DomainResult<RefreshTokenRotation> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            // lock ไว้กัน refresh พร้อมกันสองครั้งด้วย token เดียวกัน
+            let current = refresh_tokens::table
+                .filter(refresh_tokens::token_hash.eq(&token_hash))
+                .select(RefreshTokenEntity::as_select())
+                .for_update()
+                .first::<RefreshTokenEntity>(conn)?;
+
+            if current.revoked_at.is_some() {
+                return Ok(RefreshTokenRotation::Revoked);
+            }
+
+            if current.used_at.is_some() {
+                revoke_family_by_id(conn, &current.family_id)?;
+
+                return Ok(RefreshTokenRotation::ReuseDetected);
+            }
+
+            let now = chrono::Utc::now().naive_utc();
+
+            diesel::update(refresh_tokens::table)
+                .filter(refresh_tokens::id.eq(current.id))
+                .set(refresh_tokens::used_at.eq(now))
+                .execute(conn)?;
+
+            insert_into(refresh_tokens::table)
+                .values(AddRefreshTokenEntity {
+                    family_id: current.family_id,
+                    token_hash: next_token_hash,
+                    account_id: current.account_id,
+                    expires_at: current.expires_at,
+                    created_at: now,
+                })
+                .execute(conn)?;
+
+            Ok(RefreshTokenRotation::Rotated)
+        })
+        .await
+    }
+    async fn revoke_family(&self, token_hash: String) -> DomainResult<()> {
+        unit_of_work(Arc::clone(&self.db_pool), move |conn| {
+            let family_id = refresh_tokens::table
+                .filter(refresh_tokens::token_hash.eq(token_hash))
+                .select(refresh_tokens::family_id)
+                .first::<String>(conn)?;
+
+            revoke_family_by_id(conn, &family_id)?;
+
+            Ok(())
+        })
+        .await
+    }
+    async fn is_session_active(&self, session_id: String, account_id: i32) -> DomainResult<bool> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = select(exists(
+                active_heads()
+                    .filter(refresh_tokens::family_id.eq(session_id))
+                    .filter(refresh_tokens::account_id.eq(account_id)),
+            ))
+            .get_result::<bool>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn active_sessions(&self, account_id: i32) -> DomainResult<Vec<RefreshTokenEntity>> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = active_heads()
+                .filter(refresh_tokens::account_id.eq(account_id))
+                .order_by(refresh_tokens::created_at.desc())
+                .select(RefreshTokenEntity::as_select())
+                .load::<RefreshTokenEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+    async fn revoke_session(&self, session_id: String, account_id: i32) -> DomainResult<()> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let revoked = diesel::update(refresh_tokens::table)
+                .filter(refresh_tokens::family_id.eq(session_id))
+                .filter(refresh_tokens::account_id.eq(account_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(chrono::Utc::now().naive_utc()))
+                .execute(conn)?;
+
+            if revoked == 0 {
+                return Err(DomainError::NotFound("Session not found".to_string()));
+            }
+
+            Ok(())
+        })
+        .await
+    }
+    async fn revoke_all_sessions(&self, account_id: i32) -> DomainResult<usize> {
+        with_connection(Arc::clone(&self.db_pool), move |conn| {
+            let result = diesel::update(refresh_tokens::table)
+                .filter(refresh_tokens::account_id.eq(account_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(chrono::Utc::now().naive_utc()))
+                .execute(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+}
@@ -1,7 +1,16 @@
 // @generated automatically by Diesel CLI.
 
 diesel::table! {
-    adventurers (id) {
+    account_roles (account_id, role) {
+        account_id -> Int4,
+        #[max_length = 255]
+        role -> Varchar,
+        granted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    accounts (id) {
         id -> Int4,
         #[max_length = 255]
         username -> Varchar,
@@ -9,18 +18,58 @@ diesel::table! {
         password -> Varchar,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        suspended_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    adventurers (id) {
+        id -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        #[max_length = 255]
+        display_name -> Nullable<Varchar>,
+        bio -> Nullable<Text>,
+        avatar_url -> Nullable<Text>,
+        deleted_at -> Nullable<Timestamp>,
+        account_id -> Int4,
     }
 }
 
 diesel::table! {
     guild_commanders (id) {
         id -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
         #[max_length = 255]
-        username -> Varchar,
+        display_name -> Nullable<Varchar>,
+        bio -> Nullable<Text>,
+        avatar_url -> Nullable<Text>,
+        deleted_at -> Nullable<Timestamp>,
+        account_id -> Int4,
+    }
+}
+
+diesel::table! {
+    login_throttles (throttle_key) {
+        #[max_length = 512]
+        throttle_key -> Varchar,
+        failed_attempts -> Int4,
+        locked_until -> Nullable<Timestamp>,
+        last_failed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    moderation_actions (id) {
+        id -> Int4,
+        admin_account_id -> Int4,
         #[max_length = 255]
-        password -> Varchar,
+        action -> Varchar,
+        target_id -> Int4,
+        details -> Nullable<Text>,
+        reason -> Nullable<Text>,
         created_at -> Timestamp,
-        updated_at -> Timestamp,
     }
 }
 
@@ -28,6 +77,23 @@ diesel::table! {
     quest_adventurer_junction (quest_id, adventurer_id) {
         quest_id -> Int4,
         adventurer_id -> Int4,
+        joined_at -> Timestamp,
+        #[max_length = 255]
+        state -> Varchar,
+    }
+}
+
+diesel::table! {
+    quest_events (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        #[max_length = 255]
+        event_type -> Varchar,
+        #[max_length = 255]
+        actor_role -> Varchar,
+        actor_id -> Int4,
+        payload -> Jsonb,
+        created_at -> Timestamp,
     }
 }
 
@@ -43,16 +109,46 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        approval_required -> Bool,
+    }
+}
+
+diesel::table! {
+    refresh_tokens (id) {
+        id -> Int4,
+        #[max_length = 64]
+        family_id -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        account_id -> Int4,
+        expires_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
     }
 }
 
+diesel::joinable!(account_roles -> accounts (account_id));
+diesel::joinable!(adventurers -> accounts (account_id));
+diesel::joinable!(guild_commanders -> accounts (account_id));
+diesel::joinable!(moderation_actions -> accounts (admin_account_id));
 diesel::joinable!(quest_adventurer_junction -> adventurers (adventurer_id));
 diesel::joinable!(quest_adventurer_junction -> quests (quest_id));
+diesel::joinable!(quest_events -> quests (quest_id));
 diesel::joinable!(quests -> guild_commanders (guild_commander_id));
+diesel::joinable!(refresh_tokens -> accounts (account_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_roles,
+    accounts,
     adventurers,
     guild_commanders,
+    login_throttles,
+    moderation_actions,
     quest_adventurer_junction,
+    quest_events,
     quests,
+    refresh_tokens,
 );
@@ -0,0 +1,3 @@
+use diesel::{define_sql_function, sql_types::Text};
+
+define_sql_function!(fn lower(x: Text) -> Text);
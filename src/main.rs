@@ -1,10 +1,17 @@
 use std::sync::Arc;
 
 use quests_tracker::{
-    config::config_loader,
-    infrastructure::{axum_http::http_serve::start, postgres::postgres_connection},
+    config::{config_loader, stage::Stage},
+    domain::{repositories::accounts::AccountRepository, value_objects::roles::Roles},
+    infrastructure::{
+        axum_http::http_serve::start,
+        jwt_authentication::jwt_keys,
+        postgres::{
+            postgres_connection, postgres_migrations, repositories::accounts::AccountPostgres,
+        },
+    },
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() {
@@ -20,7 +27,12 @@ async fn main() {
         }
     };
 
-    info!("Server is running on port {}", dotenv_env.server.port);
+    // `migrate` รัน migrations แล้วจบ ส่วน `--migrate` รัน migrations ก่อน start server
+    // subcommand ต้องเป็น argument แรก ไม่งั้น username ที่ชื่อ migrate จะกลายเป็นคำสั่ง
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let subcommand = args.first().map(String::as_str);
+    let migrate_only = subcommand == Some("migrate");
+    let migrate_on_startup = migrate_only || args.iter().any(|arg| arg == "--migrate");
 
     let postgres_pool = match postgres_connection::establish_connection(&dotenv_env.database.url) {
         Ok(pool) => pool,
@@ -30,6 +42,83 @@ async fn main() {
         }
     };
 
+    if migrate_on_startup {
+        match postgres_migrations::run_pending_migrations(&postgres_pool) {
+            Ok(applied) if applied.is_empty() => info!("No pending migrations"),
+            Ok(applied) => info!("Applied migrations: {}", applied.join(", ")),
+            Err(e) => {
+                error!("Failed to run migrations: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if migrate_only {
+        return;
+    }
+
+    // `grant-admin <username>` ให้ role Admin กับ account ที่มีอยู่แล้วแล้วจบ ใช้สร้าง admin คนแรก
+    if subcommand == Some("grant-admin") {
+        let Some(username) = args.get(1) else {
+            error!("Usage: grant-admin <username>");
+            std::process::exit(1);
+        };
+
+        let account_repository = AccountPostgres::new(Arc::new(postgres_pool.clone()));
+
+        let granted = match account_repository.find_by_username(username.clone()).await {
+            Ok(account) => {
+                account_repository
+                    .grant_role(account.id, Roles::Admin)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = granted {
+            error!("Failed to grant Admin role to {}: {}", username, e);
+            std::process::exit(1);
+        }
+
+        info!("Granted Admin role to {}", username);
+        return;
+    }
+
+    let schema_drift = match postgres_migrations::check_schema_drift(&postgres_pool) {
+        Ok(schema_drift) => schema_drift,
+        Err(e) => {
+            error!("Failed to check schema drift: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if !schema_drift.is_empty() {
+        let stage = config_loader::get_stage();
+
+        let message = format!(
+            "Database schema drift detected (pending: [{}], unknown: [{}])",
+            schema_drift.pending.join(", "),
+            schema_drift.unknown.join(", ")
+        );
+
+        if stage == Stage::Production {
+            error!("{}, refusing to start in {} stage", message, stage);
+            std::process::exit(1);
+        }
+
+        warn!(
+            "{}, run with `--migrate` to apply pending migrations",
+            message
+        );
+    }
+
+    if let Err(e) = jwt_keys::init(&dotenv_env.jwt) {
+        error!("Failed to load JWT keys: {}", e);
+        std::process::exit(1);
+    }
+
+    info!("Server is running on port {}", dotenv_env.server.port);
+
     start(Arc::new(dotenv_env), Arc::new(postgres_pool))
         .await
         .expect("Failed to start server");
@@ -0,0 +1,66 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        repositories::adventurers::AdventurerRepository,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        repositories::adventurers::AdventurerPostgres, schema::quest_events,
+    },
+};
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn deactivate_records_a_left_event_for_each_open_quest() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+
+    // quest ที่ออกเดินทางไปแล้วยังต้องเก็บ crew ไว้ ถอนตัวเฉพาะ quest ที่ยัง Open
+    let quest_ids = [
+        (QuestStatuses::Open, CrewMembershipStates::Joined),
+        (QuestStatuses::Open, CrewMembershipStates::Pending),
+        (QuestStatuses::InJourney, CrewMembershipStates::Joined),
+    ]
+    .into_iter()
+    .map(|(status, state)| {
+        let quest_id = seed_quest(&mut conn, guild_commander_id, status);
+        seed_membership(&mut conn, quest_id, adventurer_id, state);
+
+        quest_id
+    })
+    .collect::<Vec<i32>>();
+
+    let repository = AdventurerPostgres::new(Arc::clone(&db_pool));
+    repository
+        .deactivate(
+            adventurer_id,
+            AddQuestEventModel::new(
+                QuestEventTypes::Left,
+                QuestEventActor::adventurer(adventurer_id),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let mut left_quest_ids = quest_events::table
+        .filter(quest_events::quest_id.eq_any(&quest_ids))
+        .filter(quest_events::event_type.eq(QuestEventTypes::Left))
+        .select(quest_events::quest_id)
+        .load::<i32>(&mut conn)
+        .unwrap();
+    left_quest_ids.sort();
+
+    assert_eq!(left_quest_ids, vec![quest_ids[0], quest_ids[1]]);
+}
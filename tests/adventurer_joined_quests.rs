@@ -0,0 +1,53 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use quests_tracker::{
+    domain::{
+        repositories::adventurers::AdventurerRepository,
+        value_objects::{
+            adventurer_model::JoinedQuestsFilter, crew_membership_states::CrewMembershipStates,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::repositories::adventurers::AdventurerPostgres,
+};
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn joined_quests_lists_only_joined_memberships() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+
+    let quest_ids = [
+        CrewMembershipStates::Joined,
+        CrewMembershipStates::Pending,
+        CrewMembershipStates::Invited,
+    ]
+    .into_iter()
+    .map(|state| {
+        let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+        seed_membership(&mut conn, quest_id, adventurer_id, state);
+
+        quest_id
+    })
+    .collect::<Vec<i32>>();
+
+    let repository = AdventurerPostgres::new(Arc::clone(&db_pool));
+    let adventurer = repository.find_by_id(adventurer_id).await.unwrap();
+
+    let joined_quests = repository
+        .joined_quests(&adventurer, &JoinedQuestsFilter::default())
+        .await
+        .unwrap();
+
+    let joined_quest_ids = joined_quests
+        .iter()
+        .map(|(quest, _, _)| quest.id)
+        .collect::<Vec<i32>>();
+
+    assert_eq!(joined_quest_ids, vec![quest_ids[0]]);
+}
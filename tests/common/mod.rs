@@ -0,0 +1,91 @@
+// fixture ที่ integration test ใช้ร่วมกัน
+// ต้องมี database ที่ migrate แล้ว (`cargo run -- migrate`): TEST_DATABASE_URL=postgres://... cargo test -- --ignored
+// แต่ละไฟล์ใน tests/ compile แยกกันและใช้ไม่ครบทุกตัว
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use diesel::{dsl::insert_into, prelude::*};
+use quests_tracker::{
+    domain::value_objects::{
+        crew_membership_states::CrewMembershipStates, quest_statuses::QuestStatuses,
+    },
+    infrastructure::postgres::{
+        postgres_connection::{establish_connection, PgPoolSquad},
+        schema::{accounts, adventurers, guild_commanders, quest_adventurer_junction, quests},
+    },
+};
+
+pub fn test_pool() -> Arc<PgPoolSquad> {
+    let database_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    Arc::new(establish_connection(&database_url).expect("Failed to connect test database"))
+}
+
+pub fn unique_name(prefix: &str) -> String {
+    format!("{}_{}", prefix, rand::random::<u32>())
+}
+
+pub fn seed_account(conn: &mut PgConnection, username: &str) -> i32 {
+    insert_into(accounts::table)
+        .values((
+            accounts::username.eq(username),
+            accounts::password.eq("password"),
+        ))
+        .returning(accounts::id)
+        .get_result::<i32>(conn)
+        .unwrap()
+}
+
+// คืน (account id, guild commander id)
+pub fn seed_guild_commander(conn: &mut PgConnection) -> (i32, i32) {
+    let account_id = seed_account(conn, &unique_name("commander"));
+
+    let guild_commander_id = insert_into(guild_commanders::table)
+        .values(guild_commanders::account_id.eq(account_id))
+        .returning(guild_commanders::id)
+        .get_result::<i32>(conn)
+        .unwrap();
+
+    (account_id, guild_commander_id)
+}
+
+// คืน (account id, adventurer id)
+pub fn seed_adventurer(conn: &mut PgConnection) -> (i32, i32) {
+    let account_id = seed_account(conn, &unique_name("adventurer"));
+
+    let adventurer_id = insert_into(adventurers::table)
+        .values(adventurers::account_id.eq(account_id))
+        .returning(adventurers::id)
+        .get_result::<i32>(conn)
+        .unwrap();
+
+    (account_id, adventurer_id)
+}
+
+pub fn seed_quest(conn: &mut PgConnection, guild_commander_id: i32, status: QuestStatuses) -> i32 {
+    insert_into(quests::table)
+        .values((
+            quests::name.eq(unique_name("quest")),
+            quests::status.eq(status),
+            quests::guild_commander_id.eq(guild_commander_id),
+        ))
+        .returning(quests::id)
+        .get_result::<i32>(conn)
+        .unwrap()
+}
+
+pub fn seed_membership(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    adventurer_id: i32,
+    state: CrewMembershipStates,
+) {
+    insert_into(quest_adventurer_junction::table)
+        .values((
+            quest_adventurer_junction::quest_id.eq(quest_id),
+            quest_adventurer_junction::adventurer_id.eq(adventurer_id),
+            quest_adventurer_junction::state.eq(state),
+        ))
+        .execute(conn)
+        .unwrap();
+}
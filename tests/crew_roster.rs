@@ -0,0 +1,43 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use quests_tracker::{
+    application::usecases::quest_viewing::QuestViewingUseCase,
+    domain::value_objects::{
+        crew_membership_states::CrewMembershipStates, quest_statuses::QuestStatuses,
+    },
+    infrastructure::postgres::repositories::quest_viewing::QuestViewingPostgres,
+};
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn crew_roster_lists_only_joined_adventurers() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    let adventurer_ids = [
+        CrewMembershipStates::Joined,
+        CrewMembershipStates::Pending,
+        CrewMembershipStates::Invited,
+    ]
+    .into_iter()
+    .map(|state| {
+        let (_, adventurer_id) = seed_adventurer(&mut conn);
+        seed_membership(&mut conn, quest_id, adventurer_id, state);
+
+        adventurer_id
+    })
+    .collect::<Vec<i32>>();
+
+    let quest_viewing_usecase =
+        QuestViewingUseCase::new(Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))));
+
+    let crew = quest_viewing_usecase.crew_roster(quest_id).await.unwrap();
+
+    assert_eq!(crew.len(), 1);
+    assert_eq!(crew[0].adventurer_id, adventurer_ids[0]);
+}
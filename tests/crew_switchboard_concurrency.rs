@@ -0,0 +1,158 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        errors::DomainError,
+        repositories::crew_switchboard::CrewSwitchboardRepository,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_adventurer_junction::QuestAdventurerJunction,
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::crew_switchboard::CrewSwitchboardPostgres,
+        schema::{quest_adventurer_junction, quest_events, quests},
+    },
+};
+
+const CONCURRENT_ADVENTURERS: usize = 16;
+const MAX_CREW: i32 = 8;
+
+fn seed_raid(db_pool: &PgPoolSquad) -> (i32, Vec<i32>) {
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    diesel::update(quests::table.find(quest_id))
+        .set(quests::max_crew.eq(MAX_CREW))
+        .execute(&mut conn)
+        .unwrap();
+
+    let adventurer_ids = (0..CONCURRENT_ADVENTURERS)
+        .map(|_| seed_adventurer(&mut conn).1)
+        .collect();
+
+    (quest_id, adventurer_ids)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn concurrent_joins_never_exceed_max_adventurers() {
+    let db_pool = test_pool();
+    let (quest_id, adventurer_ids) = seed_raid(&db_pool);
+    let crew_switchboard_repository = Arc::new(CrewSwitchboardPostgres::new(Arc::clone(&db_pool)));
+
+    let handles = adventurer_ids
+        .into_iter()
+        .map(|adventurer_id| {
+            let repository = Arc::clone(&crew_switchboard_repository);
+            tokio::spawn(async move {
+                repository
+                    .join(
+                        QuestAdventurerJunction {
+                            quest_id,
+                            adventurer_id,
+                        },
+                        AddQuestEventModel::new(
+                            QuestEventTypes::Joined,
+                            QuestEventActor::adventurer(adventurer_id),
+                        ),
+                    )
+                    .await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut joined = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(CrewMembershipStates::Joined) => joined += 1,
+            Err(DomainError::Conflict(_)) => {}
+            Ok(state) => panic!("Unexpected membership state: {}", state),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    let mut conn = db_pool.get().unwrap();
+    let crew_size = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+
+    // join ที่ถูก rollback ต้องไม่ทิ้ง event ไว้
+    let joined_events = quest_events::table
+        .filter(quest_events::quest_id.eq(quest_id))
+        .filter(quest_events::event_type.eq(QuestEventTypes::Joined))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+
+    assert_eq!(joined, MAX_CREW);
+    assert_eq!(crew_size, MAX_CREW as i64);
+    assert_eq!(joined_events, MAX_CREW as i64);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn concurrent_leaves_remove_the_membership_once() {
+    let db_pool = test_pool();
+    let (quest_id, adventurer_ids) = seed_raid(&db_pool);
+    let adventurer_id = adventurer_ids[0];
+    let crew_switchboard_repository = Arc::new(CrewSwitchboardPostgres::new(Arc::clone(&db_pool)));
+
+    seed_membership(
+        &mut db_pool.get().unwrap(),
+        quest_id,
+        adventurer_id,
+        CrewMembershipStates::Joined,
+    );
+
+    let handles = (0..CONCURRENT_ADVENTURERS)
+        .map(|_| {
+            let repository = Arc::clone(&crew_switchboard_repository);
+            tokio::spawn(async move {
+                repository
+                    .leave(
+                        QuestAdventurerJunction {
+                            quest_id,
+                            adventurer_id,
+                        },
+                        AddQuestEventModel::new(
+                            QuestEventTypes::Left,
+                            QuestEventActor::adventurer(adventurer_id),
+                        ),
+                    )
+                    .await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut left = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(()) => left += 1,
+            Err(DomainError::NotFound(_)) => {}
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    // leave ที่ไม่ได้ลบอะไรต้องไม่ทิ้ง event ไว้
+    let left_events = quest_events::table
+        .filter(quest_events::quest_id.eq(quest_id))
+        .filter(quest_events::event_type.eq(QuestEventTypes::Left))
+        .count()
+        .get_result::<i64>(&mut db_pool.get().unwrap())
+        .unwrap();
+
+    assert_eq!(left, 1);
+    assert_eq!(left_events, 1);
+}
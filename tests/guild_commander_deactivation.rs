@@ -0,0 +1,113 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        errors::DomainError,
+        repositories::guild_commanders::GuildCommanderRepository,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::guild_commanders::GuildCommanderPostgres,
+        schema::{quest_adventurer_junction, quest_events, quests},
+    },
+};
+
+fn seed_crew(conn: &mut PgConnection, quest_id: i32, state: CrewMembershipStates) {
+    let (_, adventurer_id) = seed_adventurer(conn);
+    seed_membership(conn, quest_id, adventurer_id, state);
+}
+
+fn is_deleted(conn: &mut PgConnection, quest_id: i32) -> bool {
+    quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::deleted_at.is_not_null())
+        .first::<bool>(conn)
+        .unwrap()
+}
+
+fn removed_event(guild_commander_id: i32) -> AddQuestEventModel {
+    AddQuestEventModel::new(
+        QuestEventTypes::Removed,
+        QuestEventActor::guild_commander(guild_commander_id),
+    )
+}
+
+async fn assert_deactivate_refused(db_pool: &Arc<PgPoolSquad>, guild_commander_id: i32) {
+    let repository = GuildCommanderPostgres::new(Arc::clone(db_pool));
+
+    let result = repository
+        .deactivate(guild_commander_id, removed_event(guild_commander_id))
+        .await;
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+
+    // ถูกปฏิเสธแล้วทุกอย่างต้อง rollback ผู้ใช้ยังเข้าใช้งานได้ตามเดิม
+    assert!(repository.find_by_id(guild_commander_id).await.is_ok());
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn deactivate_is_refused_while_an_open_quest_has_crew() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    seed_crew(&mut conn, quest_id, CrewMembershipStates::Joined);
+
+    assert_deactivate_refused(&db_pool, guild_commander_id).await;
+    assert!(!is_deleted(&mut conn, quest_id));
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn deactivate_is_refused_while_a_quest_has_failed() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Failed);
+
+    assert_deactivate_refused(&db_pool, guild_commander_id).await;
+    assert!(!is_deleted(&mut conn, quest_id));
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn deactivate_removes_open_quests_without_crew() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    seed_crew(&mut conn, quest_id, CrewMembershipStates::Pending);
+
+    let repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
+    repository
+        .deactivate(guild_commander_id, removed_event(guild_commander_id))
+        .await
+        .unwrap();
+
+    let leftover_memberships = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+
+    let removed_events = quest_events::table
+        .filter(quest_events::quest_id.eq(quest_id))
+        .filter(quest_events::event_type.eq(QuestEventTypes::Removed))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+
+    assert!(is_deleted(&mut conn, quest_id));
+    assert_eq!(leftover_memberships, 0);
+    assert_eq!(removed_events, 1);
+}
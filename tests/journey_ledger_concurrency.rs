@@ -0,0 +1,89 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_guild_commander, seed_quest, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        errors::DomainError,
+        repositories::journey_ledger::JourneyLedgerRepository,
+        value_objects::{
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::journey_ledger::JourneyLedgerPostgres,
+        schema::quest_events,
+    },
+};
+
+const CONCURRENT_REQUESTS: usize = 8;
+
+fn seed_quest_in_journey(db_pool: &PgPoolSquad) -> (i32, i32) {
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::InJourney);
+
+    (quest_id, guild_commander_id)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn concurrent_finishes_settle_on_a_single_outcome() {
+    let db_pool = test_pool();
+    let (quest_id, guild_commander_id) = seed_quest_in_journey(&db_pool);
+    let journey_ledger_repository = Arc::new(JourneyLedgerPostgres::new(Arc::clone(&db_pool)));
+
+    // ครึ่งหนึ่งขอจบแบบสำเร็จ อีกครึ่งขอจบแบบล้มเหลว พร้อมกัน
+    let handles = (0..CONCURRENT_REQUESTS)
+        .map(|i| {
+            let repository = Arc::clone(&journey_ledger_repository);
+            tokio::spawn(async move {
+                let actor = QuestEventActor::guild_commander(guild_commander_id);
+                if i % 2 == 0 {
+                    repository
+                        .to_completed(
+                            quest_id,
+                            guild_commander_id,
+                            AddQuestEventModel::new(QuestEventTypes::Completed, actor),
+                        )
+                        .await
+                } else {
+                    repository
+                        .to_failed(
+                            quest_id,
+                            guild_commander_id,
+                            AddQuestEventModel::new(QuestEventTypes::Failed, actor),
+                        )
+                        .await
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut succeeded = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(_) => succeeded += 1,
+            Err(DomainError::InvalidStateTransition(_)) => {}
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    let mut conn = db_pool.get().unwrap();
+    let finish_events = quest_events::table
+        .filter(quest_events::quest_id.eq(quest_id))
+        .filter(
+            quest_events::event_type.eq_any([QuestEventTypes::Completed, QuestEventTypes::Failed]),
+        )
+        .select(quest_events::payload)
+        .load::<serde_json::Value>(&mut conn)
+        .unwrap();
+
+    assert_eq!(succeeded, 1);
+    assert_eq!(finish_events.len(), 1);
+    assert_eq!(finish_events[0]["from_status"], "InJourney");
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::test_pool;
+use diesel::{dsl::insert_into, prelude::*};
+use quests_tracker::{
+    application::usecases::authentication::AuthenticationUseCase,
+    domain::errors::DomainError,
+    infrastructure::{
+        argon2_hashing,
+        jwt_authentication::authentication_model::LoginModel,
+        postgres::{
+            repositories::{
+                accounts::AccountPostgres, login_throttles::LoginThrottlePostgres,
+                refresh_tokens::RefreshTokenPostgres,
+            },
+            schema::{accounts, login_throttles},
+        },
+    },
+};
+
+fn failed_attempts(conn: &mut PgConnection, throttle_key: &str) -> i32 {
+    login_throttles::table
+        .filter(login_throttles::throttle_key.eq(throttle_key))
+        .select(login_throttles::failed_attempts)
+        .first::<i32>(conn)
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn unknown_username_and_wrong_password_fail_the_same_way() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let suffix = rand::random::<u32>();
+    let existing_username = format!("existing_{}", suffix);
+    let unknown_username = format!("unknown_{}", suffix);
+    let client_ip = format!("198.51.100.{}", suffix % 250);
+
+    insert_into(accounts::table)
+        .values((
+            accounts::username.eq(&existing_username),
+            accounts::password.eq(argon2_hashing::hash("Sturdy-pass-91".to_string()).unwrap()),
+        ))
+        .execute(&mut conn)
+        .unwrap();
+
+    // IP สุ่มอาจชนกับ test รอบก่อน เลยล้างตัวนับของ IP นี้ก่อน
+    diesel::delete(login_throttles::table)
+        .filter(login_throttles::throttle_key.eq(format!("ip:{}", client_ip)))
+        .execute(&mut conn)
+        .unwrap();
+
+    let authentication_usecase = AuthenticationUseCase::new(
+        Arc::new(AccountPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(RefreshTokenPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LoginThrottlePostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let mut errors = Vec::new();
+    for username in [&unknown_username, &existing_username] {
+        let result = authentication_usecase
+            .login(
+                LoginModel {
+                    username: username.clone(),
+                    password: "Wrong-pass-91".to_string(),
+                },
+                Some(client_ip.clone()),
+                None,
+            )
+            .await;
+
+        match result {
+            Err(e) => errors.push(e),
+            Ok(_) => panic!("Login with a wrong password must fail"),
+        }
+    }
+
+    assert!(matches!(errors[0], DomainError::Unauthorized(_)));
+    assert_eq!(errors[0].to_string(), errors[1].to_string());
+
+    assert_eq!(
+        failed_attempts(&mut conn, &format!("username:{}", unknown_username)),
+        1
+    );
+    assert_eq!(
+        failed_attempts(&mut conn, &format!("username:{}", existing_username)),
+        1
+    );
+    assert_eq!(failed_attempts(&mut conn, &format!("ip:{}", client_ip)), 2);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn without_a_client_ip_only_the_username_is_counted() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let username = format!("unknown_{}", rand::random::<u32>());
+
+    let authentication_usecase = AuthenticationUseCase::new(
+        Arc::new(AccountPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(RefreshTokenPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LoginThrottlePostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let result = authentication_usecase
+        .login(
+            LoginModel {
+                username: username.clone(),
+                password: "Wrong-pass-91".to_string(),
+            },
+            None,
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Unauthorized(_))));
+    assert_eq!(
+        failed_attempts(&mut conn, &format!("username:{}", username)),
+        1
+    );
+}
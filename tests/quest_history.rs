@@ -0,0 +1,76 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_guild_commander, seed_quest, test_pool};
+use diesel::{dsl::insert_into, prelude::*};
+use quests_tracker::{
+    application::usecases::quest_viewing::QuestViewingUseCase,
+    domain::value_objects::{
+        quest_event_model::{AddQuestEventModel, QuestEventActor, QuestHistoryFilter},
+        quest_event_types::QuestEventTypes,
+        quest_statuses::QuestStatuses,
+    },
+    infrastructure::postgres::{
+        repositories::quest_viewing::QuestViewingPostgres, schema::quest_events,
+    },
+};
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn public_history_is_paged_and_hides_moderation_reasons() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    let events = (0..3)
+        .map(|_| {
+            AddQuestEventModel::new(QuestEventTypes::StatusOverridden, QuestEventActor::admin(1))
+                .with_detail("reason", "spam report")
+                .with_detail("from_status", QuestStatuses::Open)
+                .to_entity(quest_id)
+        })
+        .collect::<Vec<_>>();
+
+    insert_into(quest_events::table)
+        .values(events)
+        .execute(&mut conn)
+        .unwrap();
+
+    let quest_viewing_usecase =
+        QuestViewingUseCase::new(Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))));
+
+    let first_page = quest_viewing_usecase
+        .history(
+            quest_id,
+            &QuestHistoryFilter {
+                page: Some(1),
+                limit: Some(2),
+            },
+        )
+        .await
+        .unwrap();
+
+    let last_page = quest_viewing_usecase
+        .history(
+            quest_id,
+            &QuestHistoryFilter {
+                page: Some(2),
+                limit: Some(2),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(first_page.total, 3);
+    assert_eq!(first_page.items.len(), 2);
+    assert_eq!(first_page.next_page, Some(2));
+    assert_eq!(last_page.items.len(), 1);
+    assert_eq!(last_page.next_page, None);
+
+    for quest_event in first_page.items.iter().chain(last_page.items.iter()) {
+        assert!(quest_event.payload.get("reason").is_none());
+        assert_eq!(quest_event.payload["from_status"], "Open");
+    }
+}
@@ -0,0 +1,222 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership, seed_quest, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        entities::quests::EditQuestEntity,
+        errors::DomainError,
+        repositories::quest_ops::QuestOpsRepository,
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        repositories::quest_ops::QuestOpsPostgres,
+        schema::{quest_events, quests},
+    },
+};
+
+fn is_deleted(conn: &mut PgConnection, quest_id: i32) -> bool {
+    quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::deleted_at.is_not_null())
+        .first::<bool>(conn)
+        .unwrap()
+}
+
+fn removed_event(guild_commander_id: i32) -> AddQuestEventModel {
+    AddQuestEventModel::new(
+        QuestEventTypes::Removed,
+        QuestEventActor::guild_commander(guild_commander_id),
+    )
+}
+
+fn edit_max_crew(max_crew: i32) -> EditQuestEntity {
+    EditQuestEntity {
+        name: None,
+        description: None,
+        min_crew: None,
+        max_crew: Some(max_crew),
+        approval_required: None,
+        updated_at: chrono::Utc::now().naive_utc(),
+    }
+}
+
+fn edited_event(guild_commander_id: i32) -> AddQuestEventModel {
+    AddQuestEventModel::new(
+        QuestEventTypes::Edited,
+        QuestEventActor::guild_commander(guild_commander_id),
+    )
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn remove_is_refused_once_the_quest_has_left_open() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Failed);
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .remove(
+            quest_id,
+            guild_commander_id,
+            removed_event(guild_commander_id),
+        )
+        .await;
+
+    let removed_events = quest_events::table
+        .filter(quest_events::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidStateTransition(_))
+    ));
+    assert!(!is_deleted(&mut conn, quest_id));
+    assert_eq!(removed_events, 0);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn remove_is_refused_while_the_quest_has_crew() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    seed_membership(
+        &mut conn,
+        quest_id,
+        adventurer_id,
+        CrewMembershipStates::Joined,
+    );
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .remove(
+            quest_id,
+            guild_commander_id,
+            removed_event(guild_commander_id),
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+    assert!(!is_deleted(&mut conn, quest_id));
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn remove_is_refused_for_another_guild_commander() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, other_guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .remove(
+            quest_id,
+            other_guild_commander_id,
+            removed_event(other_guild_commander_id),
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Forbidden(_))));
+    assert!(!is_deleted(&mut conn, quest_id));
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn edit_is_refused_while_the_quest_has_crew() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    seed_membership(
+        &mut conn,
+        quest_id,
+        adventurer_id,
+        CrewMembershipStates::Joined,
+    );
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .edit(
+            quest_id,
+            guild_commander_id,
+            edit_max_crew(4),
+            edited_event(guild_commander_id),
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Conflict(_))));
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn edit_checks_crew_limits_against_the_locked_quest() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    diesel::update(quests::table.find(quest_id))
+        .set(quests::min_crew.eq(3))
+        .execute(&mut conn)
+        .unwrap();
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .edit(
+            quest_id,
+            guild_commander_id,
+            edit_max_crew(2),
+            edited_event(guild_commander_id),
+        )
+        .await;
+
+    let max_crew = quests::table
+        .find(quest_id)
+        .select(quests::max_crew)
+        .first::<i32>(&mut conn)
+        .unwrap();
+
+    assert!(matches!(result, Err(DomainError::Validation(_))));
+    assert_ne!(max_crew, 2);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn edit_is_refused_once_the_quest_has_left_open() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::InJourney);
+
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let result = repository
+        .edit(
+            quest_id,
+            guild_commander_id,
+            edit_max_crew(4),
+            edited_event(guild_commander_id),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidStateTransition(_))
+    ));
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common::{seed_adventurer, seed_guild_commander, seed_membership};
+use diesel::{
+    connection::InstrumentationEvent,
+    dsl::insert_into,
+    prelude::*,
+    r2d2::{ConnectionManager, CustomizeConnection, Error as PoolError, Pool},
+};
+use quests_tracker::{
+    application::usecases::quest_viewing::QuestViewingUseCase,
+    domain::value_objects::{
+        board_checking_filter::BoardCheckingFilter, crew_membership_states::CrewMembershipStates,
+        quest_statuses::QuestStatuses,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
+        schema::quests,
+    },
+};
+
+const SEEDED_QUESTS: usize = 12;
+
+#[derive(Debug)]
+struct QueryCounter(Arc<AtomicUsize>);
+
+impl CustomizeConnection<PgConnection, PoolError> for QueryCounter {
+    fn on_acquire(&self, conn: &mut PgConnection) -> Result<(), PoolError> {
+        let counter = Arc::clone(&self.0);
+        conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+            if let InstrumentationEvent::StartQuery { .. } = event {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        Ok(())
+    }
+}
+
+fn counted_pool(counter: Arc<AtomicUsize>) -> Arc<PgPoolSquad> {
+    let database_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    let pool = Pool::builder()
+        .test_on_check_out(false)
+        .connection_customizer(Box::new(QueryCounter(counter)))
+        .build(ConnectionManager::<PgConnection>::new(database_url))
+        .expect("Failed to connect test database");
+    Arc::new(pool)
+}
+
+fn seed_board(db_pool: &PgPoolSquad) -> String {
+    let mut conn = db_pool.get().unwrap();
+    let prefix = format!("board_{}", rand::random::<u32>());
+
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+
+    for i in 0..SEEDED_QUESTS {
+        let quest_id = insert_into(quests::table)
+            .values((
+                quests::name.eq(format!("{}_{}", prefix, i)),
+                quests::status.eq(QuestStatuses::Open),
+                quests::guild_commander_id.eq(guild_commander_id),
+            ))
+            .returning(quests::id)
+            .get_result::<i32>(&mut conn)
+            .unwrap();
+
+        if i % 2 == 0 {
+            seed_membership(
+                &mut conn,
+                quest_id,
+                adventurer_id,
+                CrewMembershipStates::Joined,
+            );
+        }
+    }
+
+    prefix
+}
+
+fn board_filter(prefix: &str, limit: i64) -> BoardCheckingFilter {
+    BoardCheckingFilter {
+        name: Some(format!("{}_", prefix)),
+        status: None,
+        page: None,
+        limit: Some(limit),
+        sort_by: None,
+        sort_direction: None,
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn board_checking_query_count_does_not_grow_with_page_size() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let db_pool = counted_pool(Arc::clone(&counter));
+    let prefix = seed_board(&db_pool);
+    let quest_viewing_usecase =
+        QuestViewingUseCase::new(Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))));
+
+    let mut query_counts = Vec::new();
+    for limit in [1, 4, SEEDED_QUESTS as i64] {
+        let before = counter.load(Ordering::SeqCst);
+        let page = quest_viewing_usecase
+            .board_checking(&board_filter(&prefix, limit))
+            .await
+            .unwrap();
+        query_counts.push(counter.load(Ordering::SeqCst) - before);
+
+        assert_eq!(page.items.len() as i64, limit);
+        assert_eq!(page.total, SEEDED_QUESTS as i64);
+    }
+
+    // หนึ่ง query สำหรับ items พร้อม crew count และอีกหนึ่งสำหรับ total
+    assert_eq!(query_counts, vec![2, 2, 2]);
+
+    let page = quest_viewing_usecase
+        .board_checking(&board_filter(&prefix, SEEDED_QUESTS as i64))
+        .await
+        .unwrap();
+    let crewed = page
+        .items
+        .iter()
+        .filter(|quest| quest.adventures_count == 1)
+        .count();
+    assert_eq!(crewed, SEEDED_QUESTS / 2);
+
+    let before = counter.load(Ordering::SeqCst);
+    let quest = quest_viewing_usecase
+        .view_details(page.items[0].id)
+        .await
+        .unwrap();
+    assert_eq!(counter.load(Ordering::SeqCst) - before, 1);
+    assert_eq!(quest.adventures_count, page.items[0].adventures_count);
+}
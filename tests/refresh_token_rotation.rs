@@ -0,0 +1,148 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{seed_account, test_pool};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        entities::refresh_tokens::{
+            AddRefreshTokenEntity, RefreshTokenEntity, RefreshTokenRotation,
+        },
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::refresh_tokens::RefreshTokenPostgres,
+        schema::refresh_tokens,
+    },
+};
+
+const CONCURRENT_REFRESHES: usize = 2;
+
+// คืน (family_id, token_hash) ของ token แรกใน family
+async fn seed_family(
+    db_pool: &Arc<PgPoolSquad>,
+    repository: &RefreshTokenPostgres,
+) -> (String, String) {
+    let mut conn = db_pool.get().unwrap();
+    let suffix = rand::random::<u32>();
+
+    let account_id = seed_account(&mut conn, &format!("sessions_{}", suffix));
+
+    let now = chrono::Utc::now().naive_utc();
+    let family_id = format!("family_{}", suffix);
+    let token_hash = format!("hash_{}_0", suffix);
+
+    repository
+        .issue(AddRefreshTokenEntity {
+            family_id: family_id.clone(),
+            token_hash: token_hash.clone(),
+            account_id,
+            expires_at: now + chrono::Duration::days(7),
+            created_at: now,
+        })
+        .await
+        .unwrap();
+
+    (family_id, token_hash)
+}
+
+fn family_tokens(conn: &mut PgConnection, family_id: &str) -> Vec<RefreshTokenEntity> {
+    refresh_tokens::table
+        .filter(refresh_tokens::family_id.eq(family_id))
+        .order_by(refresh_tokens::id.asc())
+        .select(RefreshTokenEntity::as_select())
+        .load::<RefreshTokenEntity>(conn)
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn rotation_marks_the_old_token_used_and_issues_a_new_head() {
+    let db_pool = test_pool();
+    let repository = RefreshTokenPostgres::new(Arc::clone(&db_pool));
+    let (family_id, token_hash) = seed_family(&db_pool, &repository).await;
+    let next_token_hash = format!("{}_next", token_hash);
+
+    let rotation = repository
+        .rotate(token_hash.clone(), next_token_hash.clone())
+        .await
+        .unwrap();
+
+    let tokens = family_tokens(&mut db_pool.get().unwrap(), &family_id);
+
+    assert_eq!(rotation, RefreshTokenRotation::Rotated);
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].token_hash, token_hash);
+    assert!(tokens[0].used_at.is_some());
+    assert_eq!(tokens[1].token_hash, next_token_hash);
+    assert!(tokens[1].used_at.is_none());
+    assert!(tokens[1].revoked_at.is_none());
+    assert_eq!(tokens[1].expires_at, tokens[0].expires_at);
+    assert!(repository
+        .is_session_active(family_id, tokens[1].account_id)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn reusing_a_rotated_token_revokes_the_whole_family() {
+    let db_pool = test_pool();
+    let repository = RefreshTokenPostgres::new(Arc::clone(&db_pool));
+    let (family_id, token_hash) = seed_family(&db_pool, &repository).await;
+
+    repository
+        .rotate(token_hash.clone(), format!("{}_next", token_hash))
+        .await
+        .unwrap();
+
+    let reuse = repository
+        .rotate(token_hash.clone(), format!("{}_stolen", token_hash))
+        .await
+        .unwrap();
+
+    let tokens = family_tokens(&mut db_pool.get().unwrap(), &family_id);
+
+    assert_eq!(reuse, RefreshTokenRotation::ReuseDetected);
+    // token ที่ใช้ซ้ำต้องไม่ได้ head ใหม่
+    assert_eq!(tokens.len(), 2);
+    assert!(tokens.iter().all(|token| token.revoked_at.is_some()));
+    assert!(!repository
+        .is_session_active(family_id, tokens[0].account_id)
+        .await
+        .unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn concurrent_refreshes_with_the_same_token_rotate_once() {
+    let db_pool = test_pool();
+    let repository = Arc::new(RefreshTokenPostgres::new(Arc::clone(&db_pool)));
+    let (family_id, token_hash) = seed_family(&db_pool, &repository).await;
+
+    let handles = (0..CONCURRENT_REFRESHES)
+        .map(|i| {
+            let repository = Arc::clone(&repository);
+            let token_hash = token_hash.clone();
+            tokio::spawn(async move {
+                let next_token_hash = format!("{}_next_{}", token_hash, i);
+                repository.rotate(token_hash, next_token_hash).await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut rotated = 0;
+    for handle in handles {
+        match handle.await.unwrap().unwrap() {
+            RefreshTokenRotation::Rotated => rotated += 1,
+            RefreshTokenRotation::ReuseDetected => {}
+            rotation => panic!("Unexpected rotation: {:?}", rotation),
+        }
+    }
+
+    let tokens = family_tokens(&mut db_pool.get().unwrap(), &family_id);
+
+    assert_eq!(rotated, 1);
+    assert_eq!(tokens.len(), 2);
+}
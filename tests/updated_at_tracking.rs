@@ -0,0 +1,662 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use common::{seed_membership, test_pool, unique_name};
+use diesel::prelude::*;
+use quests_tracker::{
+    domain::{
+        entities::{
+            accounts::RegisterAccountEntity,
+            adventurers::UpdateAdventurerProfileEntity,
+            guild_commanders::UpdateGuildCommanderProfileEntity,
+            moderation_actions::AddModerationActionEntity,
+            quests::{AddQuestEntity, EditQuestEntity},
+        },
+        repositories::{
+            accounts::AccountRepository, admin::AdminRepository, adventurers::AdventurerRepository,
+            guild_commanders::GuildCommanderRepository, journey_ledger::JourneyLedgerRepository,
+            quest_ops::QuestOpsRepository,
+        },
+        value_objects::{
+            crew_membership_states::CrewMembershipStates,
+            moderation_actions::ModerationActions,
+            quest_event_model::{AddQuestEventModel, QuestEventActor},
+            quest_event_types::QuestEventTypes,
+            quest_statuses::QuestStatuses,
+            roles::Roles,
+        },
+    },
+    infrastructure::postgres::{
+        repositories::{
+            accounts::AccountPostgres, admin::AdminPostgres, adventurers::AdventurerPostgres,
+            guild_commanders::GuildCommanderPostgres, journey_ledger::JourneyLedgerPostgres,
+            quest_ops::QuestOpsPostgres,
+        },
+        schema::{accounts, adventurers, guild_commanders, quests},
+    },
+};
+
+// seed ทุกแถวด้วยเวลาเก่า ถ้า method ไหนลืมแตะ updated_at จะเห็นทันที
+fn stale() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn now() -> NaiveDateTime {
+    chrono::Utc::now().naive_utc()
+}
+
+// คืน (account id, guild commander id)
+fn seed_guild_commander(conn: &mut PgConnection) -> (i32, i32) {
+    let (account_id, guild_commander_id) = common::seed_guild_commander(conn);
+
+    diesel::update(accounts::table.find(account_id))
+        .set(accounts::updated_at.eq(stale()))
+        .execute(conn)
+        .unwrap();
+    diesel::update(guild_commanders::table.find(guild_commander_id))
+        .set(guild_commanders::updated_at.eq(stale()))
+        .execute(conn)
+        .unwrap();
+
+    (account_id, guild_commander_id)
+}
+
+// คืน (account id, adventurer id)
+fn seed_adventurer(conn: &mut PgConnection) -> (i32, i32) {
+    let (account_id, adventurer_id) = common::seed_adventurer(conn);
+
+    diesel::update(accounts::table.find(account_id))
+        .set(accounts::updated_at.eq(stale()))
+        .execute(conn)
+        .unwrap();
+    diesel::update(adventurers::table.find(adventurer_id))
+        .set(adventurers::updated_at.eq(stale()))
+        .execute(conn)
+        .unwrap();
+
+    (account_id, adventurer_id)
+}
+
+fn seed_quest(conn: &mut PgConnection, guild_commander_id: i32, status: QuestStatuses) -> i32 {
+    let quest_id = common::seed_quest(conn, guild_commander_id, status);
+
+    diesel::update(quests::table.find(quest_id))
+        .set(quests::updated_at.eq(stale()))
+        .execute(conn)
+        .unwrap();
+
+    quest_id
+}
+
+fn quest_updated_at(conn: &mut PgConnection, quest_id: i32) -> NaiveDateTime {
+    quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::updated_at)
+        .first::<NaiveDateTime>(conn)
+        .unwrap()
+}
+
+fn adventurer_updated_at(conn: &mut PgConnection, adventurer_id: i32) -> NaiveDateTime {
+    adventurers::table
+        .filter(adventurers::id.eq(adventurer_id))
+        .select(adventurers::updated_at)
+        .first::<NaiveDateTime>(conn)
+        .unwrap()
+}
+
+fn guild_commander_updated_at(conn: &mut PgConnection, guild_commander_id: i32) -> NaiveDateTime {
+    guild_commanders::table
+        .filter(guild_commanders::id.eq(guild_commander_id))
+        .select(guild_commanders::updated_at)
+        .first::<NaiveDateTime>(conn)
+        .unwrap()
+}
+
+fn account_updated_at(conn: &mut PgConnection, account_id: i32) -> NaiveDateTime {
+    accounts::table
+        .filter(accounts::id.eq(account_id))
+        .select(accounts::updated_at)
+        .first::<NaiveDateTime>(conn)
+        .unwrap()
+}
+
+fn assert_touched(updated_at: NaiveDateTime, before: NaiveDateTime) {
+    // เผื่อ clock ของ app กับ database ต่างกันเล็กน้อย
+    assert!(
+        updated_at >= before - chrono::Duration::seconds(5),
+        "updated_at was not maintained: {}",
+        updated_at
+    );
+}
+
+fn quest_event(event_type: QuestEventTypes, guild_commander_id: i32) -> AddQuestEventModel {
+    AddQuestEventModel::new(
+        event_type,
+        QuestEventActor::guild_commander(guild_commander_id),
+    )
+}
+
+fn moderation_action(
+    admin_account_id: i32,
+    action: ModerationActions,
+    target_id: i32,
+) -> AddModerationActionEntity {
+    AddModerationActionEntity {
+        admin_account_id,
+        action,
+        target_id,
+        details: None,
+        reason: None,
+        created_at: now(),
+    }
+}
+
+#[test]
+#[ignore = "requires TEST_DATABASE_URL"]
+fn trigger_touches_updated_at_unless_the_query_sets_it() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let before = now();
+
+    diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .set(quests::name.eq(unique_name("renamed")))
+        .execute(&mut conn)
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+
+    let explicit = stale() + chrono::Duration::days(1);
+
+    diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .set((
+            quests::name.eq(unique_name("renamed")),
+            quests::updated_at.eq(explicit),
+        ))
+        .execute(&mut conn)
+        .unwrap();
+
+    assert_eq!(quest_updated_at(&mut conn, quest_id), explicit);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn quest_ops_add_sets_updated_at() {
+    let db_pool = test_pool();
+    let (_, guild_commander_id) = seed_guild_commander(&mut db_pool.get().unwrap());
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    let quest_id = repository
+        .add(
+            AddQuestEntity {
+                name: unique_name("quest"),
+                description: None,
+                status: QuestStatuses::Open,
+                guild_commander_id,
+                min_crew: 1,
+                max_crew: 4,
+                approval_required: false,
+                created_at: now(),
+                updated_at: now(),
+            },
+            quest_event(QuestEventTypes::Created, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(
+        quest_updated_at(&mut db_pool.get().unwrap(), quest_id),
+        before,
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn quest_ops_edit_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .edit(
+            quest_id,
+            guild_commander_id,
+            EditQuestEntity {
+                name: Some(unique_name("edited")),
+                description: None,
+                min_crew: None,
+                max_crew: None,
+                approval_required: None,
+                updated_at: now(),
+            },
+            quest_event(QuestEventTypes::Edited, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn quest_ops_remove_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .remove(
+            quest_id,
+            guild_commander_id,
+            quest_event(QuestEventTypes::Removed, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn quest_ops_transfer_ownership_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let (_, next_guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let repository = QuestOpsPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .transfer_ownership(
+            quest_id,
+            guild_commander_id,
+            next_guild_commander_id,
+            quest_event(QuestEventTypes::OwnershipTransferred, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn journey_ledger_in_journey_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+
+    // ออกเดินทางได้ต้องมี crew ครบ min_crew
+    seed_membership(
+        &mut conn,
+        quest_id,
+        adventurer_id,
+        CrewMembershipStates::Joined,
+    );
+
+    let repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .in_journey(
+            quest_id,
+            guild_commander_id,
+            quest_event(QuestEventTypes::Departed, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn journey_ledger_to_completed_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::InJourney);
+    let repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .to_completed(
+            quest_id,
+            guild_commander_id,
+            quest_event(QuestEventTypes::Completed, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn journey_ledger_to_failed_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::InJourney);
+    let repository = JourneyLedgerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .to_failed(
+            quest_id,
+            guild_commander_id,
+            quest_event(QuestEventTypes::Failed, guild_commander_id),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn admin_restore_quest_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (admin_account_id, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+
+    diesel::update(quests::table)
+        .filter(quests::id.eq(quest_id))
+        .set((quests::deleted_at.eq(now()), quests::updated_at.eq(stale())))
+        .execute(&mut conn)
+        .unwrap();
+
+    let repository = AdminPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .restore_quest(
+            moderation_action(admin_account_id, ModerationActions::RestoreQuest, quest_id),
+            AddQuestEventModel::new(
+                QuestEventTypes::Restored,
+                QuestEventActor::admin(admin_account_id),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn admin_override_quest_status_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (admin_account_id, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Completed);
+    let repository = AdminPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .override_quest_status(
+            QuestStatuses::Failed,
+            moderation_action(
+                admin_account_id,
+                ModerationActions::OverrideQuestStatus,
+                quest_id,
+            ),
+            AddQuestEventModel::new(
+                QuestEventTypes::StatusOverridden,
+                QuestEventActor::admin(admin_account_id),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn admin_suspend_and_unsuspend_account_touch_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (admin_account_id, _) = seed_guild_commander(&mut conn);
+    let (account_id, _) = seed_adventurer(&mut conn);
+    let repository = AdminPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .suspend_account(moderation_action(
+            admin_account_id,
+            ModerationActions::SuspendAccount,
+            account_id,
+        ))
+        .await
+        .unwrap();
+
+    assert_touched(account_updated_at(&mut conn, account_id), before);
+
+    diesel::update(accounts::table)
+        .filter(accounts::id.eq(account_id))
+        .set(accounts::updated_at.eq(stale()))
+        .execute(&mut conn)
+        .unwrap();
+
+    repository
+        .unsuspend_account(moderation_action(
+            admin_account_id,
+            ModerationActions::UnsuspendAccount,
+            account_id,
+        ))
+        .await
+        .unwrap();
+
+    assert_touched(account_updated_at(&mut conn, account_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn adventurer_register_sets_updated_at() {
+    let db_pool = test_pool();
+    let repository = AdventurerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    let adventurer_id = repository
+        .register(RegisterAccountEntity {
+            username: unique_name("adventurer"),
+            password: "password".to_string(),
+            created_at: now(),
+            updated_at: now(),
+        })
+        .await
+        .unwrap();
+
+    assert_touched(
+        adventurer_updated_at(&mut db_pool.get().unwrap(), adventurer_id),
+        before,
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn adventurer_update_profile_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+    let repository = AdventurerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    let adventurer = repository
+        .update_profile(
+            adventurer_id,
+            UpdateAdventurerProfileEntity {
+                display_name: Some(Some("Wanderer".to_string())),
+                bio: None,
+                avatar_url: None,
+                updated_at: now(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_touched(adventurer.updated_at, before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn adventurer_deactivate_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, adventurer_id) = seed_adventurer(&mut conn);
+    let repository = AdventurerPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .deactivate(
+            adventurer_id,
+            AddQuestEventModel::new(
+                QuestEventTypes::Left,
+                QuestEventActor::adventurer(adventurer_id),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(adventurer_updated_at(&mut conn, adventurer_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn guild_commander_register_sets_updated_at() {
+    let db_pool = test_pool();
+    let repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    let guild_commander_id = repository
+        .register(RegisterAccountEntity {
+            username: unique_name("commander"),
+            password: "password".to_string(),
+            created_at: now(),
+            updated_at: now(),
+        })
+        .await
+        .unwrap();
+
+    assert_touched(
+        guild_commander_updated_at(&mut db_pool.get().unwrap(), guild_commander_id),
+        before,
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn guild_commander_update_profile_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    let guild_commander = repository
+        .update_profile(
+            guild_commander_id,
+            UpdateGuildCommanderProfileEntity {
+                display_name: Some(Some("Marshal".to_string())),
+                bio: None,
+                avatar_url: None,
+                updated_at: now(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_touched(guild_commander.updated_at, before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn guild_commander_deactivate_touches_updated_at_of_profile_and_quests() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (_, guild_commander_id) = seed_guild_commander(&mut conn);
+    let quest_id = seed_quest(&mut conn, guild_commander_id, QuestStatuses::Open);
+    let repository = GuildCommanderPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .deactivate(
+            guild_commander_id,
+            AddQuestEventModel::new(
+                QuestEventTypes::Removed,
+                QuestEventActor::guild_commander(guild_commander_id),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_touched(
+        guild_commander_updated_at(&mut conn, guild_commander_id),
+        before,
+    );
+    assert_touched(quest_updated_at(&mut conn, quest_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn account_change_password_touches_updated_at() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (account_id, _) = seed_adventurer(&mut conn);
+    let repository = AccountPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .change_password(account_id, "new-password".to_string())
+        .await
+        .unwrap();
+
+    assert_touched(account_updated_at(&mut conn, account_id), before);
+}
+
+#[tokio::test]
+#[ignore = "requires TEST_DATABASE_URL"]
+async fn account_grant_role_touches_updated_at_of_reactivated_profile() {
+    let db_pool = test_pool();
+    let mut conn = db_pool.get().unwrap();
+    let (account_id, adventurer_id) = seed_adventurer(&mut conn);
+
+    diesel::update(adventurers::table)
+        .filter(adventurers::id.eq(adventurer_id))
+        .set((
+            adventurers::deleted_at.eq(now()),
+            adventurers::updated_at.eq(stale()),
+        ))
+        .execute(&mut conn)
+        .unwrap();
+
+    let repository = AccountPostgres::new(Arc::clone(&db_pool));
+    let before = now();
+
+    repository
+        .grant_role(account_id, Roles::Adventurer)
+        .await
+        .unwrap();
+
+    assert_touched(adventurer_updated_at(&mut conn, adventurer_id), before);
+}